@@ -0,0 +1,202 @@
+//! The error type shared by every solution and the runner.
+
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::sync::Arc;
+
+/// Everything that can go wrong while reading, parsing or solving a puzzle.
+#[derive(Debug, Clone)]
+pub enum Error {
+	/// A file could not be read or written.
+	Io {
+		/// The file that was being accessed.
+		path: String,
+		/// What went wrong.
+		source: Arc<io::Error>,
+	},
+	/// The puzzle input does not have the expected format.
+	Parse {
+		/// The 1-based line the error was found on, if known.
+		line: Option<usize>,
+		/// The 1-based column the error was found on, if known.
+		column: Option<usize>,
+		/// What was expected or found instead.
+		message: String,
+	},
+	/// The input parsed fine, but describes a puzzle that makes no sense.
+	InvalidInput(String),
+	/// The part has not been solved yet.
+	NotImplemented,
+	/// The solver could not find an answer for this input.
+	Unsolvable(String),
+	/// The part ran longer than it was allowed to and was cancelled, see [crate::solution::Context::check_cancelled].
+	TimedOut,
+	/// Another error, tagged with the day and part it happened in.
+	Day {
+		/// The day that failed.
+		day: i32,
+		/// The part that failed, or `None` if it happened before any part ran.
+		part: Option<i32>,
+		/// The error itself.
+		source: Box<Error>,
+	},
+}
+
+/// The kind of an [Error], ignoring the day it happened in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+	/// See [Error::Io].
+	Io,
+	/// See [Error::Parse].
+	Parse,
+	/// See [Error::InvalidInput].
+	InvalidInput,
+	/// See [Error::NotImplemented].
+	NotImplemented,
+	/// See [Error::Unsolvable].
+	Unsolvable,
+	/// See [Error::TimedOut].
+	TimedOut,
+}
+
+impl ErrorKind {
+	/// A short, stable name for the kind, used in machine readable output.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			ErrorKind::Io => "io",
+			ErrorKind::Parse => "parse",
+			ErrorKind::InvalidInput => "invalid_input",
+			ErrorKind::NotImplemented => "not_implemented",
+			ErrorKind::Unsolvable => "unsolvable",
+			ErrorKind::TimedOut => "timed_out",
+		}
+	}
+
+	/// Exit code used by the runner when a part fails with this kind of error.
+	/// 1 is reserved for usage errors and 2 for answers that don't match the answers file.
+	pub fn exit_code(&self) -> i32 {
+		match self {
+			ErrorKind::Io => 3,
+			ErrorKind::Parse => 4,
+			ErrorKind::InvalidInput => 5,
+			ErrorKind::NotImplemented => 6,
+			ErrorKind::Unsolvable => 7,
+			ErrorKind::TimedOut => 8,
+		}
+	}
+}
+
+impl Error {
+	/// An I/O error on the file at `path`.
+	pub fn io<S: Into<String>>(
+		path: S,
+		source: io::Error,
+	) -> Error {
+		Error::Io { path: path.into(), source: Arc::new(source) }
+	}
+
+	/// A parse error that doesn't know where in the input it happened yet,
+	/// see [Error::at_line] and [Error::at_column].
+	pub fn parse<S: Into<String>>(message: S) -> Error {
+		Error::Parse { line: None, column: None, message: message.into() }
+	}
+
+	/// See [Error::InvalidInput].
+	pub fn invalid<S: Into<String>>(message: S) -> Error {
+		Error::InvalidInput(message.into())
+	}
+
+	/// See [Error::Unsolvable].
+	pub fn unsolvable<S: Into<String>>(message: S) -> Error {
+		Error::Unsolvable(message.into())
+	}
+
+	/// Sets the (1-based) line of a parse error, unless it already has one.
+	/// Other kinds of errors are turned into parse errors on that line.
+	pub fn at_line(self, line: usize) -> Error {
+		match self {
+			Error::Parse { line: None, column, message } => Error::Parse { line: Some(line), column, message },
+			Error::InvalidInput(message) => Error::Parse { line: Some(line), column: None, message },
+			e => e,
+		}
+	}
+
+	/// Sets the (1-based) column of a parse error, unless it already has one.
+	pub fn at_column(self, column: usize) -> Error {
+		match self {
+			Error::Parse { line, column: None, message } => Error::Parse { line, column: Some(column), message },
+			e => e,
+		}
+	}
+
+	/// Moves the column of a parse error right, for errors found in a slice of a line.
+	pub fn offset_column(self, offset: usize) -> Error {
+		match self {
+			Error::Parse { line, column: Some(column), message } => Error::Parse { line, column: Some(column + offset), message },
+			e => e,
+		}
+	}
+
+	/// Moves the line of a parse error down, for errors found in a later section of the input.
+	pub fn offset_line(self, offset: usize) -> Error {
+		match self {
+			Error::Parse { line: Some(line), column, message } => Error::Parse { line: Some(line + offset), column, message },
+			e => e,
+		}
+	}
+
+	/// Tags the error with the day and part it happened in.
+	pub fn in_day(self, day: i32, part: Option<i32>) -> Error {
+		Error::Day { day, part, source: Box::new(self) }
+	}
+
+	/// The underlying error, without the day and part it happened in.
+	pub fn root(&self) -> &Error {
+		match self {
+			Error::Day { source, .. } => source.root(),
+			e => e,
+		}
+	}
+
+	/// The kind of the underlying error.
+	pub fn kind(&self) -> ErrorKind {
+		match self.root() {
+			Error::Io { .. } => ErrorKind::Io,
+			Error::Parse { .. } => ErrorKind::Parse,
+			Error::InvalidInput(_) => ErrorKind::InvalidInput,
+			Error::NotImplemented => ErrorKind::NotImplemented,
+			Error::Unsolvable(_) => ErrorKind::Unsolvable,
+			Error::TimedOut => ErrorKind::TimedOut,
+			Error::Day { .. } => unreachable!("root never returns a day error"),
+		}
+	}
+}
+
+impl Display for Error {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Error::Io { path, source } => write!(f, "I/O error on {path}: {source}"),
+			Error::Parse { line: Some(line), column: Some(column), message } =>
+				write!(f, "Parse error on line {line}, column {column}: {message}"),
+			Error::Parse { line: Some(line), column: None, message } =>
+				write!(f, "Parse error on line {line}: {message}"),
+			Error::Parse { message, .. } => write!(f, "Parse error: {message}"),
+			Error::InvalidInput(message) => write!(f, "Invalid input: {message}"),
+			Error::NotImplemented => write!(f, "Solution not yet implemented!"),
+			Error::Unsolvable(message) => write!(f, "Unsolvable: {message}"),
+			Error::TimedOut => write!(f, "Timed out"),
+			Error::Day { day, part: Some(part), source } => write!(f, "Day {day:02} part {part}: {source}"),
+			Error::Day { day, part: None, source } => write!(f, "Day {day:02}: {source}"),
+		}
+	}
+}
+
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Error::Io { source, .. } => Some(source.as_ref()),
+			Error::Day { source, .. } => Some(source.as_ref()),
+			_ => None,
+		}
+	}
+}
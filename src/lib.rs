@@ -0,0 +1,20 @@
+//! Solutions to Advent of Code 2023, usable without going through the command line runner.
+//!
+//! Every day implements [solution::Solution]. The quickest way in is to hand it the puzzle input:
+//!
+//! ```no_run
+//! use aoc2023::solution::{Day01, Solution};
+//!
+//! let input = std::fs::read_to_string("data/01").unwrap();
+//! println!("{}", Day01::solve(1, &input).unwrap());
+//! ```
+//!
+//! Days can also be looked up by number through [solution::find] and [solution::registry],
+//! and built for input from a file, stdin or a string through [solution::InputSource].
+
+#![warn(missing_docs)]
+
+pub mod error;
+pub mod log;
+pub mod solution;
+pub mod util;
@@ -0,0 +1,139 @@
+//! Levelled logging to stderr, so debugging output never ends up between the answers on stdout.
+//!
+//! Solutions log through the [crate::error!], [crate::warn!], [crate::info!], [crate::debug!] and [crate::trace!] macros.
+//! Only warnings and errors are written unless the level is raised with [set_level],
+//! which the runner does for `-v`/`-vv` and the [LEVEL_VAR] environment variable.
+
+use std::fmt::{Arguments, Display, Formatter};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+use std::time::Instant;
+
+/// The environment variable the runner reads the level from, e.g. `AOC_LOG=debug`.
+pub const LEVEL_VAR: &str = "AOC_LOG";
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+static START: OnceLock<Instant> = OnceLock::new();
+
+/// How important a log message is, from most to least.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+	/// Something went wrong.
+	Error,
+	/// Something looks off, but the part carries on.
+	Warn,
+	/// What the runner and the solutions are up to.
+	Info,
+	/// Intermediate results, useful when an answer is wrong.
+	Debug,
+	/// Every single step, this can be a lot.
+	Trace,
+}
+
+impl Level {
+	const ALL: [Level; 5] = [Level::Error, Level::Warn, Level::Info, Level::Debug, Level::Trace];
+
+	/// The level `-v` repeated `count` times selects: [Level::Warn] without it,
+	/// [Level::Debug] for `-v` and [Level::Trace] from `-vv` on.
+	pub fn from_verbosity(count: usize) -> Level {
+		match count {
+			0 => Level::Warn,
+			1 => Level::Debug,
+			_ => Level::Trace,
+		}
+	}
+
+	/// The lowercase name of the level, as accepted by [LEVEL_VAR].
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Level::Error => "error",
+			Level::Warn => "warn",
+			Level::Info => "info",
+			Level::Debug => "debug",
+			Level::Trace => "trace",
+		}
+	}
+}
+
+impl Display for Level {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+impl FromStr for Level {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Level::ALL.into_iter()
+			.find(|level| level.as_str().eq_ignore_ascii_case(s.trim()))
+			.ok_or(format!("Unknown log level '{s}', expected error, warn, info, debug or trace"))
+	}
+}
+
+/// Only log messages at `level` or more important from now on.
+pub fn set_level(level: Level) {
+	LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// The least important level that is currently logged.
+pub fn level() -> Level {
+	Level::ALL[LEVEL.load(Ordering::Relaxed) as usize]
+}
+
+/// Whether messages at `level` are written. Check this before building expensive messages.
+pub fn enabled(level: Level) -> bool {
+	level <= self::level()
+}
+
+/// Writes a single line to stderr with the time since the first message, the level and where it came from.
+/// Use the macros instead, they skip formatting the message when the level is disabled.
+pub fn write(
+	level: Level,
+	target: &str,
+	args: Arguments,
+) {
+	let elapsed = START.get_or_init(Instant::now).elapsed();
+	eprintln!("[{:>9.3}s {:<5} {target}] {args}", elapsed.as_secs_f64(), level.as_str().to_uppercase());
+}
+
+/// Logs a message at the given [Level], formatted like [format!].
+#[macro_export]
+macro_rules! log {
+	($level:expr, $($arg:tt)+) => {
+		if $crate::log::enabled($level) {
+			$crate::log::write($level, module_path!(), format_args!($($arg)+));
+		}
+	};
+}
+
+/// Logs a message at [Level::Error].
+#[macro_export]
+macro_rules! error {
+	($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+/// Logs a message at [Level::Warn].
+#[macro_export]
+macro_rules! warn {
+	($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+/// Logs a message at [Level::Info].
+#[macro_export]
+macro_rules! info {
+	($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+/// Logs a message at [Level::Debug].
+#[macro_export]
+macro_rules! debug {
+	($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+/// Logs a message at [Level::Trace].
+#[macro_export]
+macro_rules! trace {
+	($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
@@ -1,20 +1,47 @@
 use std::env;
+use std::io;
+use std::io::IsTerminal;
+use std::ops::RangeInclusive;
 use std::process::exit;
-use getopts::Options;
-use solution::*;
+use getopts::{Matches, Options};
+use aoc2023::error::{Error, ErrorKind};
+use aoc2023::log;
+use aoc2023::log::Level;
+use aoc2023::solution;
+use aoc2023::solution::{InputSource, Params, Registration, Status, DEFAULT_VARIANT};
+use runner::{Answers, BenchResult, Format, Outcome, RunOptions, RunResult};
 
-mod solution;
-mod util;
+mod runner;
 
 fn main() {
 	let args: Vec<String> = env::args().collect();
 	let program = args[0].clone();
 
 	let mut opts = Options::new();
-	opts.reqopt("d", "day", "specify which day to run", "[1..25]");
-	opts.optopt("f", "file", "override which file to use", "FILE");
+	opts.optopt("d", "day", "specify which day to run", "[1..25]");
+	opts.optflag("a", "all", "run every day and print a summary table");
+	opts.optopt("", "days", "run a range of days and print a summary table", "FROM..TO");
+	opts.optopt("f", "file", "override which file to use, - reads stdin", "FILE");
+	opts.optflag("e", "example", "run the examples from the puzzle text and check their answers");
+	opts.optmulti("p", "param", "change a constant of the puzzle, see the parameters below", "KEY=VALUE");
 	opts.optflag("", "one", "toggle running part 1");
 	opts.optflag("", "two", "toggle running part 2");
+	opts.optflag("", "check", "compare the answers against the answers file");
+	opts.optflag("", "record", "write the answers into the answers file");
+	opts.optopt("", "answers", "override which answers file to use", "FILE");
+	opts.optopt("", "variant", "solve the parts with another implementation, see --list-variants", "NAME");
+	opts.optflag("", "compare", "run every variant of the selected parts and flag the ones that disagree");
+	opts.optopt("", "threads", "how many threads the parallel days use, 1 runs them in order", "N");
+	opts.optopt("", "timeout", "cancel parts that run longer than this, e.g. 30s or 500ms", "DURATION");
+	opts.optflag("", "watch", "run the day again every time its input file changes");
+	opts.optflag("", "explain", "print the commentary solutions give on their answers");
+	opts.optflag("", "render", "print pictures of the state of the days that draw one to stderr");
+	opts.optopt("", "format", "how to print the results (default text)", "json|csv|text");
+	opts.optopt("", "bench", "benchmark the selected parts over N runs", "N");
+	opts.optopt("", "warmup", "number of untimed runs before benchmarking (default 1)", "N");
+	opts.optflag("l", "list", "list every available day");
+	opts.optflag("", "list-variants", "list the other implementations of every part");
+	opts.optflagmulti("v", "verbose", "log what the solutions are doing to stderr, -vv for every step");
 	opts.optflag("h", "help", "print this help menu");
 
 	let matches = opts.parse(&args[1..])
@@ -28,63 +55,375 @@ fn main() {
 		exit(0);
 	}
 
-	let day = match matches.opt_str("d") {
-		None => print_error("Day must be specified!", &program, &opts),
-		Some(x) => x.parse::<i32>()
-			.unwrap_or_else(|_| print_error("Day must be a number!", &program, &opts)),
-	};
+	if matches.free.first().is_some_and(|command| command == "new-day") {
+		let day = matches.free.get(1)
+			.and_then(|day| day.parse::<i32>().ok())
+			.unwrap_or_else(|| print_error("new-day needs the number of the day!", &program, &opts));
+		let title = match matches.free[2..].join(" ") {
+			title if title.is_empty() => "TODO".to_string(),
+			title => title,
+		};
+		match runner::new_day(day, &title) {
+			Ok(written) => written.iter().for_each(|path| println!("Wrote {path}")),
+			Err(e) => {
+				println!("{e}");
+				exit(e.kind().exit_code());
+			}
+		}
+		exit(0);
+	}
+
+	if matches.free.first().is_some_and(|command| command == "readme") {
+		let registrations = solution::registry();
+		// With --bench, every part that is solved gets timed on its input first
+		let timings = matches.opt_str("bench").map(|runs| {
+			let runs = runs.parse::<usize>()
+				.unwrap_or_else(|_| print_error("Benchmark runs must be a number!", &program, &opts));
+			registrations.iter()
+				.flat_map(|registration| {
+					let day = registration.info.day;
+					let parts = [1, 2].into_iter()
+						.filter(|&part| registration.status[part as usize - 1] != Status::Unimplemented)
+						.collect::<Vec<i32>>();
+					let params = Params::defaults(registration.params);
+					let solution = registration.build(default_input(day));
+					runner::bench_day(day, &parts, DEFAULT_VARIANT, solution.as_ref(), &params, 1, runs)
+				}).collect::<Vec<BenchResult>>()
+		});
+		match runner::write_readme(&registrations, timings.as_deref()) {
+			Ok(path) => println!("Wrote {path}"),
+			Err(e) => {
+				println!("{e}");
+				exit(e.kind().exit_code());
+			}
+		}
+		exit(0);
+	}
+
+	if matches.opt_present("l") {
+		runner::print_registry(&solution::registry());
+		exit(0);
+	}
 
-	let file = matches.opt_str("f")
-		.unwrap_or_else(|| format!("data/{day:02}"));
+	if matches.opt_present("list-variants") {
+		runner::print_variants(&solution::registry());
+		exit(0);
+	}
 
 	let part_one = matches.opt_present("one");
 	let part_two = matches.opt_present("two");
-	let solution: Box<dyn Solution> = match day {
-		1 => Box::new(Day01::new(&file)),
-		2 => Box::new(Day02::new(&file)),
-		3 => Box::new(Day03::new(&file)),
-		4 => Box::new(Day04::new(&file)),
-		5 => Box::new(Day05::new(&file)),
-		6 => Box::new(Day06::new(&file)),
-		7 => Box::new(Day07::new(&file)),
-		8 => Box::new(Day08::new(&file)),
-		9 => Box::new(Day09::new(&file)),
-		10 => Box::new(Day10::new(&file)),
-		11 => Box::new(Day11::new(&file)),
-		12 => Box::new(Day12::new(&file)),
-		13 => Box::new(Day13::new(&file)),
-		14 => Box::new(Day14::new(&file)),
-		15 => Box::new(Day15::new(&file)),
-		16 => Box::new(Day16::new(&file)),
-		17 => Box::new(Day17::new(&file)),
-		18 => Box::new(Day18::new(&file)),
-		19 => Box::new(Day19::new(&file)),
-		20 => Box::new(Day20::new(&file)),
-		21 => Box::new(Day21::new(&file)),
-		_ => Box::new(PlaceholderSolution::new(&file)),
+	let check = matches.opt_present("check");
+	let record = matches.opt_present("record");
+	let explain = matches.opt_present("explain");
+	let example = matches.opt_present("e");
+	let param_args = matches.opt_strs("p");
+	let compare = matches.opt_present("compare");
+	let watch = matches.opt_present("watch");
+	let render = matches.opt_present("render");
+	let variant = matches.opt_str("variant").unwrap_or_else(|| DEFAULT_VARIANT.to_string());
+	let verbosity = matches.opt_count("v");
+	if verbosity > 0 {
+		log::set_level(Level::from_verbosity(verbosity));
+	} else if let Ok(level) = env::var(log::LEVEL_VAR) {
+		log::set_level(level.parse().unwrap_or_else(|e: String| print_error(&e, &program, &opts)));
+	}
+	if let Some(threads) = matches.opt_str("threads") {
+		runner::configure_threads(&threads).unwrap_or_else(|e| print_error(&e, &program, &opts));
+	}
+	let timeout = matches.opt_str("timeout")
+		.map(|t| runner::parse_timeout(&t).unwrap_or_else(|e| print_error(&e, &program, &opts)));
+	// Progress bars would only get in the way of piped output and of the log lines
+	let progress = io::stdout().is_terminal() && !log::enabled(Level::Debug);
+	let options = |params: Params| RunOptions { variant: variant.clone(), params, timeout, progress, render };
+	let format = matches.opt_str("format")
+		.map(|f| f.parse::<Format>().unwrap_or_else(|e| print_error(&e, &program, &opts)))
+		.unwrap_or(Format::Text);
+	let answers_file = matches.opt_str("answers")
+		.unwrap_or_else(|| "data/answers".to_string());
+
+	let days = if matches.opt_present("a") {
+		Some(1..=25)
+	} else {
+		matches.opt_str("days").map(|days| runner::parse_day_range(&days)
+			.unwrap_or_else(|e| print_error(&e, &program, &opts)))
 	};
 
-	if !part_one && !part_two {
-		print_error("Must specify at least one part to run!", &program, &opts);
-	} else if part_one && part_two {
-		run(1, || solution.part_one());
-		println!();
-		run(2, || solution.part_two());
-	} else if part_one {
-		run(1, || solution.part_one());
-	} else if part_two {
-		run(2, || solution.part_two());
+	if example && ["f", "p", "bench", "check", "record"].iter().any(|&opt| matches.opt_present(opt)) {
+		print_error("Examples can not be combined with --file, --param, --bench, --check or --record!", &program, &opts);
+	}
+	// The answers file only holds the answers to the puzzle as it was given
+	if (check || record) && !param_args.is_empty() {
+		print_error("Changed parameters can not be combined with --check or --record!", &program, &opts);
+	}
+	if (check || record) && variant != DEFAULT_VARIANT {
+		print_error("Variants can not be combined with --check or --record!", &program, &opts);
+	}
+	if compare && ["e", "variant", "bench", "check", "record", "format"].iter().any(|&opt| matches.opt_present(opt)) {
+		print_error("Comparing can not be combined with --example, --variant, --bench, --check, --record or --format!", &program, &opts);
 	}
+	if watch && (days.is_some() || ["e", "compare", "bench", "check", "record", "format"].iter().any(|&opt| matches.opt_present(opt))) {
+		print_error("Watching can not be combined with several days, --example, --compare, --bench, --check, --record or --format!", &program, &opts);
+	}
+
+	// With several days, every parameter only goes to the days that have it
+	let several_days = days.is_some();
+	if let Some(days) = &days {
+		let registrations = days.clone().filter_map(solution::find).collect::<Vec<Registration>>();
+		let unknown = param_args.iter()
+			.find(|arg| !registrations.iter().any(|r| r.params.iter().any(|p| p.name == param_name(arg))));
+		if let Some(arg) = unknown {
+			print_error(&format!("None of the selected days has a parameter '{}'", param_name(arg)), &program, &opts);
+		}
+
+		let parts = selected_parts(part_one, part_two);
+		if registrations.iter().all(|r| variant_parts(r, &parts, &variant).is_empty()) {
+			print_error(&format!("None of the selected days has a variant named '{variant}'"), &program, &opts);
+		}
+	}
+
+	if compare {
+		let parts = selected_parts(part_one, part_two);
+		let mut comparisons = Vec::new();
+		for (day, source) in targets(days, &matches, &program, &opts) {
+			if let Some(registration) = solution::find(day) {
+				let params = day_params(&registration, &param_args, several_days)
+					.unwrap_or_else(|e| print_error(&e.to_string(), &program, &opts));
+				comparisons.extend(runner::compare_day(&parts, &registration, source, &options(params)));
+			}
+		}
+
+		if comparisons.is_empty() {
+			print_error("None of the selected parts has variants to compare, see --list-variants", &program, &opts);
+		}
+		runner::print_comparison(&comparisons);
+		if let Some(code) = comparisons.iter().find_map(|c| c.result.exit_code()) { exit(code); }
+		return;
+	}
+
+	if let Some(runs) = matches.opt_str("bench") {
+		let runs = runs.parse::<usize>()
+			.unwrap_or_else(|_| print_error("Benchmark runs must be a number!", &program, &opts));
+		let warmup = match matches.opt_str("warmup") {
+			None => 1,
+			Some(x) => x.parse::<usize>()
+				.unwrap_or_else(|_| print_error("Warm-up runs must be a number!", &program, &opts)),
+		};
+
+		let parts = selected_parts(part_one, part_two);
+		let mut results = Vec::new();
+		for (day, source) in targets(days, &matches, &program, &opts) {
+			if let Some(registration) = solution::find(day) {
+				let params = day_params(&registration, &param_args, several_days)
+					.unwrap_or_else(|e| print_error(&e.to_string(), &program, &opts));
+				let parts = variant_parts(&registration, &parts, &variant);
+				let solution = registration.build(source);
+				results.extend(runner::bench_day(day, &parts, &variant, solution.as_ref(), &params, warmup, runs));
+			}
+		}
+
+		runner::print_bench_table(&results);
+		if let Some(error) = results.iter().find_map(|r| r.solve.as_ref().err()) {
+			exit(error.kind().exit_code());
+		}
+		return;
+	}
+
+	let mut answers = if check || record {
+		Answers::load(&answers_file).unwrap_or_else(|e| {
+			println!("{e}");
+			exit(e.kind().exit_code());
+		})
+	} else {
+		Answers::default()
+	};
+
+	let results = if let Some(days) = days {
+		let parts = selected_parts(part_one, part_two);
+		let mut results = days.flat_map(|day| {
+			match solution::find(day) {
+				Some(registration) if example => {
+					runner::run_examples(&variant_parts(&registration, &parts, &variant), &registration, &options(Params::default()))
+				}
+				Some(registration) => {
+					let params = day_params(&registration, &param_args, several_days)
+						.unwrap_or_else(|e| print_error(&e.to_string(), &program, &opts));
+					let parts = variant_parts(&registration, &parts, &variant);
+					runner::run_day(day, &parts, registration.build(default_input(day)).into(), &options(params))
+				}
+				None if variant == DEFAULT_VARIANT => parts.iter().map(|&part| runner::not_implemented(day, part)).collect(),
+				None => Vec::new(),
+			}
+		}).collect::<Vec<RunResult>>();
+
+		if check { answers.check(&mut results); }
+		match format {
+			Format::Text => {
+				runner::print_table(&results);
+				if explain { runner::print_explanations(&results); }
+			}
+			Format::Json => runner::print_json(&results),
+			Format::Csv => runner::print_csv(&results),
+		}
+		results
+	} else {
+		let (day, source) = single_day(&matches, &program, &opts);
+		let registration = solution::find(day).unwrap_or_else(|| {
+			println!("Day {day} is not implemented yet!\n");
+			print_usage(&program, &opts);
+			exit(1);
+		});
+
+		if !part_one && !part_two {
+			print_error("Must specify at least one part to run!", &program, &opts);
+		}
+
+		let parts = variant_parts(&registration, &selected_parts(part_one, part_two), &variant);
+		if parts.is_empty() {
+			print_error(&format!("Day {day} has no variant named '{variant}' for the selected parts"), &program, &opts);
+		}
+
+		if watch {
+			let params = day_params(&registration, &param_args, several_days)
+				.unwrap_or_else(|e| print_error(&e.to_string(), &program, &opts));
+			match &source {
+				InputSource::File(path) => runner::watch_day(&parts, &registration, path, &options(params)),
+				_ => print_error("Watching needs an input file, not stdin!", &program, &opts),
+			}
+		}
+
+		let mut results = if example {
+			let results = runner::run_examples(&parts, &registration, &options(Params::default()));
+			if results.is_empty() {
+				println!("Day {day} has no examples with answers for the selected parts");
+				exit(1);
+			}
+			results
+		} else {
+			let params = day_params(&registration, &param_args, several_days)
+				.unwrap_or_else(|e| print_error(&e.to_string(), &program, &opts));
+			runner::run_day(day, &parts, registration.build(source).into(), &options(params))
+		};
+		if check { answers.check(&mut results); }
+		match format {
+			Format::Text => for (i, result) in results.iter().enumerate() {
+				if i > 0 { println!(); }
+				print_result(result, explain);
+			},
+			Format::Json => runner::print_json(&results),
+			Format::Csv => runner::print_csv(&results),
+		}
+		results
+	};
+
+	if (check || example) && format == Format::Text { runner::print_mismatches(&results); }
+	if record {
+		let recorded = answers.record(&results);
+		answers.save().unwrap_or_else(|e| {
+			println!("{e}");
+			exit(e.kind().exit_code());
+		});
+		// Keep stdout parseable for the machine readable formats
+		match format {
+			Format::Text => println!("\nRecorded {recorded} answer(s) in {answers_file}"),
+			_ => eprintln!("Recorded {recorded} answer(s) in {answers_file}"),
+		}
+	}
+
+	// The summary table of several days already shows which ones are missing
+	if !several_days && results.iter().any(|r| matches!(r.outcome, Outcome::NotImplemented)) {
+		exit(ErrorKind::NotImplemented.exit_code());
+	}
+	if let Some(code) = results.iter().find_map(RunResult::exit_code) { exit(code); }
+}
+
+fn single_day(
+	matches: &Matches,
+	program: &str,
+	opts: &Options,
+) -> (i32, InputSource) {
+	let day = match matches.opt_str("d") {
+		None => print_error("Day must be specified!", program, opts),
+		Some(x) => x.parse::<i32>()
+			.unwrap_or_else(|_| print_error("Day must be a number!", program, opts)),
+	};
+
+	let source = matches.opt_str("f")
+		.map(|file| InputSource::from_arg(&file))
+		.unwrap_or_else(|| default_input(day));
+
+	(day, source)
+}
+
+/// The days to run with the input of each, either the selected range or the single day.
+fn targets(
+	days: Option<RangeInclusive<i32>>,
+	matches: &Matches,
+	program: &str,
+	opts: &Options,
+) -> Vec<(i32, InputSource)> {
+	match days {
+		Some(days) => days.map(|day| (day, default_input(day))).collect(),
+		None => vec![single_day(matches, program, opts)],
+	}
+}
+
+fn default_input(day: i32) -> InputSource {
+	InputSource::File(format!("data/{day:02}"))
 }
 
-fn run<F>(
-	part: i32,
-	function: F,
-) where F: Fn() -> Result<String, String> {
-	println!("Running part {}...", part);
-	match function() {
-		Ok(result) => println!("{result}"),
-		Err(error) => println!("Error: {error}"),
+/// Runs both parts unless exactly one of them was asked for.
+fn selected_parts(
+	part_one: bool,
+	part_two: bool,
+) -> Vec<i32> {
+	match (part_one, part_two) {
+		(false, true) => vec![2],
+		(true, false) => vec![1],
+		_ => vec![1, 2],
+	}
+}
+
+/// The parameters of a day, set from the `--param` arguments. When `several_days` run,
+/// arguments for parameters this day doesn't have are left for the other days.
+fn day_params(
+	registration: &Registration,
+	args: &[String],
+	several_days: bool,
+) -> Result<Params, Error> {
+	let args = args.iter()
+		.filter(|arg| !several_days || registration.params.iter().any(|p| p.name == param_name(arg)))
+		.collect::<Vec<&String>>();
+	Params::parse(registration.params, &args)
+}
+
+/// The parts that can be solved with `variant`.
+fn variant_parts(
+	registration: &Registration,
+	parts: &[i32],
+	variant: &str,
+) -> Vec<i32> {
+	parts.iter()
+		.copied()
+		.filter(|&part| registration.variant_names(part).contains(&variant))
+		.collect()
+}
+
+fn param_name(arg: &str) -> &str {
+	arg.split_once('=').map_or(arg, |(name, _)| name).trim()
+}
+
+fn print_result(
+	result: &RunResult,
+	explain: bool,
+) {
+	println!("Running part {}...", result.part);
+	if explain {
+		for explanation in &result.explanations { println!("{explanation}"); }
+	}
+	match &result.outcome {
+		Outcome::Passed(answer) | Outcome::Mismatch { actual: answer, .. } => println!("{answer}"),
+		Outcome::Failed(error) => println!("Error: {}", error.root()),
+		Outcome::NotImplemented => println!("Error: Solution not yet implemented!"),
 	}
 }
 
@@ -92,8 +431,22 @@ fn print_usage(
 	program: &str,
 	opts: &Options,
 ) {
-	let brief = format!("Usage: {program} [options]");
-	println!("{}", opts.usage(&brief))
+	let days = solution::registry().iter()
+		.map(|r| r.info.day.to_string())
+		.collect::<Vec<String>>()
+		.join(", ");
+	let brief = format!("Usage: {program} [options]\n       {program} new-day N [TITLE]\n       {program} readme [--bench N]\n\nAvailable days: {days}\n\n\
+		Exit codes: 1 usage, 2 mismatched answer, 3 I/O error, 4 parse error,\n\
+		5 invalid input, 6 not implemented, 7 unsolvable, 8 timed out\n\n\
+		Set {}=error|warn|info|debug|trace to choose what is logged without -v", log::LEVEL_VAR);
+	println!("{}", opts.usage(&brief));
+
+	println!("Parameters:");
+	for registration in solution::registry() {
+		for param in registration.params {
+			println!("    Day {:02}: {param}", registration.info.day);
+		}
+	}
 }
 
 fn print_error(
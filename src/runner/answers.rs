@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use aoc2023::error::Error;
+use crate::runner::{Outcome, RunResult};
+
+/// Known correct answers, stored as one `day part answer` row per line.
+#[derive(Default)]
+pub struct Answers {
+	path: String,
+	entries: BTreeMap<(i32, i32), String>,
+}
+
+impl Answers {
+	pub fn load(path: &str) -> Result<Answers, Error> {
+		let content = match fs::read_to_string(path) {
+			Ok(content) => content,
+			Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+			Err(e) => return Err(Error::io(path, e)),
+		};
+
+		let mut entries = BTreeMap::new();
+		for (i, line) in content.lines().enumerate() {
+			if line.trim().is_empty() || line.starts_with('#') { continue; }
+
+			let mut split = line.splitn(3, ' ');
+			let day = split.next().and_then(|d| d.parse::<i32>().ok());
+			let part = split.next().and_then(|p| p.parse::<i32>().ok());
+			match (day, part, split.next()) {
+				(Some(day), Some(part), Some(answer)) => entries.insert((day, part), answer.to_string()),
+				_ => return Err(Error::parse(format!("Invalid row in answers file {path}: '{line}'")).at_line(i + 1)),
+			};
+		}
+
+		Ok(Answers { path: path.to_string(), entries })
+	}
+
+	pub fn save(&self) -> Result<(), Error> {
+		let content = self.entries.iter()
+			.map(|((day, part), answer)| format!("{day:02} {part} {answer}\n"))
+			.collect::<String>();
+		fs::write(&self.path, content)
+			.map_err(|e| Error::io(&self.path, e))
+	}
+
+	/// Marks every result whose answer differs from the recorded one as a mismatch.
+	pub fn check(
+		&self,
+		results: &mut [RunResult],
+	) {
+		for result in results {
+			let expected = match self.entries.get(&(result.day, result.part)) {
+				Some(expected) => expected,
+				None => continue,
+			};
+
+			if let Outcome::Passed(actual) = &result.outcome {
+				if actual.to_string() != *expected {
+					result.outcome = Outcome::Mismatch {
+						expected: expected.clone(),
+						actual: actual.clone(),
+					};
+				}
+			}
+		}
+	}
+
+	/// Stores the answer of every successful result, replacing what was there before.
+	pub fn record(
+		&mut self,
+		results: &[RunResult],
+	) -> usize {
+		let mut recorded = 0;
+		for result in results {
+			if let Outcome::Passed(answer) = &result.outcome {
+				self.entries.insert((result.day, result.part), answer.to_string());
+				recorded += 1;
+			}
+		}
+
+		recorded
+	}
+}
+
+pub fn print_mismatches(results: &[RunResult]) {
+	for result in results {
+		if let Outcome::Mismatch { expected, actual } = &result.outcome {
+			println!("\nDay {:02} part {}: answer changed", result.day, result.part);
+			println!("- expected: {expected}");
+			println!("+ actual:   {actual}");
+		}
+	}
+}
@@ -0,0 +1,143 @@
+use std::time::{Duration, Instant};
+use aoc2023::error::Error;
+use crate::runner::table::print_grid;
+use aoc2023::solution::{Answer, Context, DynSolution, Params};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+	pub min: Duration,
+	pub median: Duration,
+	pub mean: Duration,
+	pub stddev: Duration,
+}
+
+impl Stats {
+	pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+		if samples.is_empty() { return None; }
+
+		let mut sorted = samples.to_vec();
+		sorted.sort();
+		let len = sorted.len();
+		let median = if len % 2 == 0 {
+			(sorted[len / 2 - 1] + sorted[len / 2]) / 2
+		} else {
+			sorted[len / 2]
+		};
+
+		let secs = sorted.iter().map(|d| d.as_secs_f64()).collect::<Vec<f64>>();
+		let mean = secs.iter().sum::<f64>() / len as f64;
+		let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / len as f64;
+
+		Some(Stats {
+			min: sorted[0],
+			median,
+			mean: Duration::from_secs_f64(mean),
+			stddev: Duration::from_secs_f64(variance.sqrt()),
+		})
+	}
+}
+
+#[derive(Debug)]
+pub struct BenchResult {
+	pub day: i32,
+	pub part: i32,
+	pub runs: usize,
+	/// Time spent turning the raw input into something the solver can use.
+	pub parse: Option<Stats>,
+	pub solve: Result<Stats, Error>,
+}
+
+/// Parses the input of a day repeatedly to time it, then benchmarks every given part
+/// against the same parsed input.
+pub fn bench_day(
+	day: i32,
+	parts: &[i32],
+	variant: &str,
+	solution: &dyn DynSolution,
+	params: &Params,
+	warmup: usize,
+	runs: usize,
+) -> Vec<BenchResult> {
+	let failed = |error: Error| parts.iter()
+		.map(|&part| BenchResult { day, part, runs: 0, parse: None, solve: Err(error.clone().in_day(day, Some(part))) })
+		.collect();
+
+	let raw = match solution.read_input() {
+		Ok(raw) => raw,
+		Err(error) => return failed(error),
+	};
+
+	let mut input = None;
+	let mut samples = Vec::with_capacity(runs);
+	for i in 0..(warmup + runs).max(1) {
+		let start = Instant::now();
+		match solution.parse(&raw) {
+			Ok(parsed) => {
+				if i >= warmup { samples.push(start.elapsed()); }
+				input = Some(parsed);
+			}
+			Err(error) => return failed(error),
+		}
+	}
+
+	let input = match input {
+		Some(input) => input,
+		None => return failed(Error::invalid("Input was never parsed")),
+	};
+
+	let parse = Stats::from_samples(&samples);
+	parts.iter()
+		.map(|&part| {
+			let run = || {
+				let ctx = Context::with_params(params.clone());
+				solution.part_variant(part, variant, input.as_ref(), &ctx)
+			};
+			bench_part(day, part, run, parse, warmup, runs)
+		})
+		.collect()
+}
+
+/// Times `run`, the solver of one part, after `warmup` untimed calls.
+fn bench_part(
+	day: i32,
+	part: i32,
+	run: impl Fn() -> Result<Answer, Error>,
+	parse: Option<Stats>,
+	warmup: usize,
+	runs: usize,
+) -> BenchResult {
+	for _ in 0..warmup {
+		if let Err(error) = run() {
+			return BenchResult { day, part, runs: 0, parse, solve: Err(error.in_day(day, Some(part))) };
+		}
+	}
+
+	let mut samples = Vec::with_capacity(runs);
+	for _ in 0..runs {
+		let start = Instant::now();
+		if let Err(error) = run() {
+			return BenchResult { day, part, runs: samples.len(), parse, solve: Err(error.in_day(day, Some(part))) };
+		}
+		samples.push(start.elapsed());
+	}
+
+	let solve = Stats::from_samples(&samples)
+		.ok_or(Error::invalid("No benchmark runs were made").in_day(day, Some(part)));
+	BenchResult { day, part, runs, parse, solve }
+}
+
+pub fn print_bench_table(results: &[BenchResult]) {
+	let rows = results.iter()
+		.map(|result| {
+			let parse = result.parse.map(|s| format!("{:.2?}", s.median)).unwrap_or("-".to_string());
+			let mut row = vec![format!("{:02}", result.day), result.part.to_string(), result.runs.to_string(), parse];
+			match &result.solve {
+				Ok(stats) => row.extend([stats.min, stats.median, stats.mean, stats.stddev].map(|d| format!("{d:.2?}"))),
+				Err(error) => row.push(format!("failed: {}", error.root())),
+			}
+			row
+		}).collect::<Vec<Vec<String>>>();
+
+	print_grid(&["Day", "Part", "Runs", "Parse", "Min", "Median", "Mean", "Stddev"], &rows);
+	println!("\nRan on {} thread(s)", rayon::current_num_threads());
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+use aoc2023::solution::{DynSolution, InputSource, Registration, DEFAULT_VARIANT};
+use crate::runner::table::print_grid;
+use crate::runner::{failed, parse_input, run_part, Outcome, RunOptions, RunResult};
+
+/// The result of one variant of a part.
+#[derive(Debug)]
+pub struct Comparison {
+	pub variant: &'static str,
+	pub result: RunResult,
+}
+
+/// Runs every variant of the given parts that have more than one on the same parsed input,
+/// marking the variants that disagree with the default one as mismatches.
+/// The variant in `options` is ignored.
+pub fn compare_day(
+	parts: &[i32],
+	registration: &Registration,
+	source: InputSource,
+	options: &RunOptions,
+) -> Vec<Comparison> {
+	let day = registration.info.day;
+	let parts = parts.iter()
+		.map(|&part| (part, registration.variant_names(part)))
+		.filter(|(_, variants)| variants.len() > 1)
+		.collect::<Vec<(i32, Vec<&'static str>)>>();
+	if parts.is_empty() { return Vec::new(); }
+
+	let solution: Arc<dyn DynSolution> = registration.build(source).into();
+	let (input, parse) = match parse_input(solution.as_ref()) {
+		Ok((input, parse)) => (Arc::from(input), parse),
+		Err(error) => return parts.iter()
+			.map(|(part, variants)| Comparison { variant: variants[0], result: failed(day, *part, error.clone()) })
+			.collect(),
+	};
+
+	let mut comparisons = Vec::new();
+	for (part, variants) in parts {
+		let mut results = variants.iter()
+			.map(|&variant| {
+				let options = RunOptions { variant: variant.to_string(), ..options.clone() };
+				Comparison { variant, result: run_part(day, part, &solution, &input, &options, parse) }
+			}).collect::<Vec<Comparison>>();
+
+		// The first variant is the default, which the others are compared against
+		if let Outcome::Passed(expected) = &results[0].result.outcome {
+			let expected = expected.to_string();
+			for comparison in results.iter_mut().skip(1) {
+				if let Outcome::Passed(actual) = &comparison.result.outcome {
+					if actual.to_string() != expected {
+						comparison.result.outcome = Outcome::Mismatch { expected: expected.clone(), actual: actual.clone() };
+					}
+				}
+			}
+		}
+		comparisons.extend(results);
+	}
+
+	comparisons
+}
+
+pub fn print_comparison(comparisons: &[Comparison]) {
+	let rows = comparisons.iter()
+		.map(|comparison| {
+			let result = &comparison.result;
+			let (answer, status) = match &result.outcome {
+				Outcome::Passed(answer) if comparison.variant == DEFAULT_VARIANT => (answer.to_string(), "reference"),
+				Outcome::Passed(answer) => (answer.to_string(), "agrees"),
+				Outcome::Failed(error) => (error.root().to_string(), "failed"),
+				Outcome::Mismatch { actual, .. } => (actual.to_string(), "DISAGREES"),
+				Outcome::NotImplemented => (String::new(), "not implemented"),
+			};
+			let solve = if result.duration.is_zero() { String::new() } else { format!("{:.2?}", result.duration) };
+
+			vec![format!("{:02}", result.day), result.part.to_string(), comparison.variant.to_string(), answer, solve, status.to_string()]
+		}).collect::<Vec<Vec<String>>>();
+
+	print_grid(&["Day", "Part", "Variant", "Answer", "Solve", "Status"], &rows);
+
+	let disagreeing = comparisons.iter().filter(|c| matches!(c.result.outcome, Outcome::Mismatch { .. })).count();
+	println!("\n{disagreeing} of {} variant(s) disagree with the default", comparisons.len());
+}
@@ -0,0 +1,26 @@
+use std::ops::RangeInclusive;
+
+/// Parses a day selection such as `5`, `3..12` or `3..=12` into an inclusive range.
+/// Both ends have to be days of the advent calendar.
+pub fn parse_day_range(s: &str) -> Result<RangeInclusive<i32>, String> {
+	let parse = |n: &str| n.trim().parse::<i32>()
+		.map_err(|_| format!("Invalid day: '{n}'"));
+
+	let (from, to) = match s.split_once("..") {
+		Some((from, to)) => (parse(from)?, parse(to.trim_start_matches('='))?),
+		None => {
+			let day = parse(s)?;
+			(day, day)
+		}
+	};
+
+	if from > to {
+		return Err(format!("Invalid day range: {from} is after {to}"));
+	}
+
+	if from < 1 || to > 25 {
+		return Err(format!("Invalid day range: {from}..{to} is not within 1..25"));
+	}
+
+	Ok(from..=to)
+}
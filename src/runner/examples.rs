@@ -0,0 +1,46 @@
+use aoc2023::solution::{InputSource, Registration};
+use crate::runner::{failed, run_day, Outcome, RunOptions, RunResult};
+
+/// Runs the given parts on every example of a day that has an answer for them,
+/// marking the results that differ from that answer as mismatches.
+/// The parameters in `options` are replaced by the ones of each example.
+pub fn run_examples(
+	parts: &[i32],
+	registration: &Registration,
+	options: &RunOptions,
+) -> Vec<RunResult> {
+	let day = registration.info.day;
+	let mut results = Vec::new();
+	for example in registration.examples {
+		let parts = parts.iter()
+			.copied()
+			.filter(|&part| example.expected(part).is_some())
+			.collect::<Vec<i32>>();
+		if parts.is_empty() { continue; }
+
+		let params = match example.params(registration.params) {
+			Ok(params) => params,
+			Err(error) => {
+				results.extend(parts.iter().map(|&part| failed(day, part, error.clone())));
+				continue;
+			}
+		};
+
+		let options = RunOptions { params, ..options.clone() };
+		let solution = registration.build(InputSource::text(example.input));
+		for mut result in run_day(day, &parts, solution.into(), &options) {
+			let expected = example.expected(result.part).unwrap_or_default();
+			if let Outcome::Passed(actual) = &result.outcome {
+				if actual.to_string() != expected {
+					result.outcome = Outcome::Mismatch {
+						expected: expected.to_string(),
+						actual: actual.clone(),
+					};
+				}
+			}
+			results.push(result);
+		}
+	}
+
+	results
+}
@@ -0,0 +1,152 @@
+use std::str::FromStr;
+use std::time::Duration;
+use aoc2023::error::ErrorKind;
+use crate::runner::{Outcome, RunResult};
+
+/// How the results of a run are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+	Text,
+	Json,
+	Csv,
+}
+
+impl FromStr for Format {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"text" => Ok(Format::Text),
+			"json" => Ok(Format::Json),
+			"csv" => Ok(Format::Csv),
+			_ => Err(format!("Unknown format '{s}', expected json, csv or text")),
+		}
+	}
+}
+
+/// The fields every machine readable format shares, with `None` for missing values.
+struct Row {
+	status: &'static str,
+	answer: Option<String>,
+	expected: Option<String>,
+	parse: Option<Duration>,
+	solve: Option<Duration>,
+	error_kind: Option<&'static str>,
+	error: Option<String>,
+}
+
+impl Row {
+	fn of(result: &RunResult) -> Row {
+		let ran = !matches!(result.outcome, Outcome::NotImplemented) && !result.duration.is_zero();
+		let mut row = Row {
+			status: "",
+			answer: None,
+			expected: None,
+			parse: ran.then_some(result.parse),
+			solve: ran.then_some(result.duration),
+			error_kind: None,
+			error: None,
+		};
+
+		match &result.outcome {
+			Outcome::Passed(answer) => {
+				row.status = "passed";
+				row.answer = Some(answer.to_string());
+			}
+			Outcome::Failed(error) => {
+				row.status = if error.kind() == ErrorKind::TimedOut { "timed_out" } else { "failed" };
+				row.error_kind = Some(error.kind().as_str());
+				row.error = Some(error.root().to_string());
+			}
+			Outcome::Mismatch { expected, actual } => {
+				row.status = "mismatch";
+				row.answer = Some(actual.to_string());
+				row.expected = Some(expected.clone());
+			}
+			Outcome::NotImplemented => row.status = "not_implemented",
+		}
+
+		row
+	}
+}
+
+/// Prints a JSON array with one object per day and part.
+pub fn print_json(results: &[RunResult]) {
+	let objects = results.iter()
+		.map(|result| {
+			let row = Row::of(result);
+			let fields = [
+				format!("\"day\": {}", result.day),
+				format!("\"part\": {}", result.part),
+				format!("\"status\": {}", json_string(row.status)),
+				format!("\"answer\": {}", json_option(row.answer.as_deref())),
+				format!("\"expected\": {}", json_option(row.expected.as_deref())),
+				format!("\"parse_ns\": {}", row.parse.map_or("null".to_string(), |d| d.as_nanos().to_string())),
+				format!("\"solve_ns\": {}", row.solve.map_or("null".to_string(), |d| d.as_nanos().to_string())),
+				format!("\"error_kind\": {}", json_option(row.error_kind)),
+				format!("\"error\": {}", json_option(row.error.as_deref())),
+				format!("\"explanations\": [{}]", result.explanations.iter()
+					.map(|e| json_string(e))
+					.collect::<Vec<String>>()
+					.join(", ")),
+			];
+			format!("  {{{}}}", fields.join(", "))
+		}).collect::<Vec<String>>();
+
+	if objects.is_empty() {
+		println!("[]");
+	} else {
+		println!("[\n{}\n]", objects.join(",\n"));
+	}
+}
+
+/// Prints a header and one comma separated row per day and part.
+pub fn print_csv(results: &[RunResult]) {
+	println!("day,part,status,answer,expected,parse_ns,solve_ns,error_kind,error");
+	for result in results {
+		let row = Row::of(result);
+		let cells = [
+			result.day.to_string(),
+			result.part.to_string(),
+			row.status.to_string(),
+			csv_cell(row.answer.as_deref().unwrap_or_default()),
+			csv_cell(row.expected.as_deref().unwrap_or_default()),
+			row.parse.map(|d| d.as_nanos().to_string()).unwrap_or_default(),
+			row.solve.map(|d| d.as_nanos().to_string()).unwrap_or_default(),
+			row.error_kind.unwrap_or_default().to_string(),
+			csv_cell(row.error.as_deref().unwrap_or_default()),
+		];
+		println!("{}", cells.join(","));
+	}
+}
+
+fn json_option(s: Option<&str>) -> String {
+	s.map_or("null".to_string(), json_string)
+}
+
+fn json_string(s: &str) -> String {
+	let mut out = String::with_capacity(s.len() + 2);
+	out.push('"');
+	for c in s.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+	out
+}
+
+/// Quotes a cell if it contains anything that would break the row apart.
+fn csv_cell(s: &str) -> String {
+	if s.contains([',', '"', '\n', '\r']) {
+		format!("\"{}\"", s.replace('"', "\"\""))
+	} else {
+		s.to_string()
+	}
+}
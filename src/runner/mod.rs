@@ -0,0 +1,170 @@
+mod answers;
+mod bench;
+mod compare;
+mod days;
+mod examples;
+mod format;
+mod readme;
+mod scaffold;
+mod table;
+mod threads;
+mod timeout;
+mod watch;
+
+use std::any::Any;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use aoc2023::error::Error;
+use aoc2023::info;
+use aoc2023::solution::{Answer, Context, DynSolution, Params};
+
+pub use answers::{print_mismatches, Answers};
+pub use bench::{bench_day, print_bench_table, BenchResult};
+pub use compare::{compare_day, print_comparison};
+pub use days::parse_day_range;
+pub use examples::run_examples;
+pub use format::{print_csv, print_json, Format};
+pub use readme::write_readme;
+pub use scaffold::new_day;
+pub use table::{print_explanations, print_registry, print_table, print_variants};
+pub use threads::configure_threads;
+pub use timeout::parse_timeout;
+pub use watch::watch_day;
+
+#[derive(Debug)]
+pub enum Outcome {
+	Passed(Answer),
+	Failed(Error),
+	Mismatch { expected: String, actual: Answer },
+	NotImplemented,
+}
+
+#[derive(Debug)]
+pub struct RunResult {
+	pub day: i32,
+	pub part: i32,
+	pub outcome: Outcome,
+	pub parse: Duration,
+	pub duration: Duration,
+	/// Commentary the part gave on its answer, only shown when asked for.
+	pub explanations: Vec<String>,
+}
+
+impl RunResult {
+	/// The exit code this result should end the program with, if it failed.
+	pub fn exit_code(&self) -> Option<i32> {
+		match &self.outcome {
+			Outcome::Failed(error) => Some(error.kind().exit_code()),
+			Outcome::Mismatch { .. } => Some(MISMATCH_EXIT_CODE),
+			_ => None,
+		}
+	}
+}
+
+/// How the parts of a day are run, besides which parts to run.
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+	/// Which implementation of the parts to use, see [aoc2023::solution::Solution::variants].
+	pub variant: String,
+	/// The parameters of the day, see [aoc2023::solution::Solution::params].
+	pub params: Params,
+	/// How long a part may run before it is cancelled.
+	pub timeout: Option<Duration>,
+	/// Whether long running parts draw a progress bar, see [aoc2023::solution::Context::progress].
+	pub progress: bool,
+	/// Whether parts print pictures of their state, see [aoc2023::solution::Context::render].
+	pub render: bool,
+}
+
+/// Exit code for answers that differ from the ones in the answers file.
+pub const MISMATCH_EXIT_CODE: i32 = 2;
+
+/// Reads the input of a solution and parses it, timing only the parsing.
+pub fn parse_input(solution: &dyn DynSolution) -> Result<(Box<dyn Any + Send + Sync>, Duration), Error> {
+	let raw = solution.read_input()?;
+	let start = Instant::now();
+	let input = solution.parse(&raw)?;
+	Ok((input, start.elapsed()))
+}
+
+/// Parses the input of a day once and runs every given part on it.
+pub fn run_day(
+	day: i32,
+	parts: &[i32],
+	solution: Arc<dyn DynSolution>,
+	options: &RunOptions,
+) -> Vec<RunResult> {
+	match parse_input(solution.as_ref()) {
+		Ok((input, parse)) => {
+			info!("Parsed the input of day {day} in {parse:.2?}");
+			let input = Arc::from(input);
+			parts.iter()
+				.map(|&part| run_part(day, part, &solution, &input, options, parse))
+				.collect()
+		}
+		Err(error) => parts.iter()
+			.map(|&part| failed(day, part, error.clone()))
+			.collect(),
+	}
+}
+
+pub fn run_part(
+	day: i32,
+	part: i32,
+	solution: &Arc<dyn DynSolution>,
+	input: &Arc<dyn Any + Send + Sync>,
+	options: &RunOptions,
+	parse: Duration,
+) -> RunResult {
+	let ctx = Context::with_params(options.params.clone())
+		.with_progress(options.progress)
+		.with_renders(options.render);
+	info!("Running part {part} of day {day} ({} variant)", options.variant);
+	let start = Instant::now();
+	let (result, explanations) = match options.timeout {
+		Some(timeout) => timeout::run_with_timeout(part, &options.variant, solution, input, ctx, timeout),
+		None => {
+			let result = solution.part_variant(part, &options.variant, input.as_ref(), &ctx);
+			(result, ctx.into_explanations())
+		}
+	};
+	let duration = start.elapsed();
+
+	let outcome = match result {
+		Ok(answer) => Outcome::Passed(answer),
+		Err(Error::NotImplemented) => Outcome::NotImplemented,
+		Err(error) => Outcome::Failed(error.in_day(day, Some(part))),
+	};
+
+	RunResult { day, part, outcome, parse, duration, explanations }
+}
+
+pub fn not_implemented(
+	day: i32,
+	part: i32,
+) -> RunResult {
+	RunResult {
+		day,
+		part,
+		outcome: Outcome::NotImplemented,
+		parse: Duration::ZERO,
+		duration: Duration::ZERO,
+		explanations: Vec::new(),
+	}
+}
+
+/// A part that failed before it could run, e.g. because its input could not be parsed.
+pub fn failed(
+	day: i32,
+	part: i32,
+	error: Error,
+) -> RunResult {
+	RunResult {
+		day,
+		part,
+		outcome: Outcome::Failed(error.in_day(day, Some(part))),
+		parse: Duration::ZERO,
+		duration: Duration::ZERO,
+		explanations: Vec::new(),
+	}
+}
@@ -0,0 +1,100 @@
+use std::fs;
+use aoc2023::error::Error;
+use aoc2023::solution::{Registration, Status};
+use crate::runner::bench::BenchResult;
+
+const README_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/README.md");
+/// The table lists days 1 to 13 on the left and 14 to 25 on the right.
+const ROWS: i32 = 13;
+
+/// Regenerates the legend and progress table of the README from the status every day declares,
+/// leaving the rest of the file alone. With `timings`, every day also gets the median time of its parts.
+/// Returns the path of the README.
+pub fn write_readme(
+	registrations: &[Registration],
+	timings: Option<&[BenchResult]>,
+) -> Result<&'static str, Error> {
+	let readme = match fs::read_to_string(README_PATH) {
+		Ok(readme) => readme,
+		Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+		Err(e) => return Err(Error::io(README_PATH, e)),
+	};
+
+	let lines = readme.lines().collect::<Vec<&str>>();
+	let start = lines.iter()
+		.position(|line| line.starts_with(" * ") || line.starts_with("| Day"))
+		.unwrap_or(lines.len());
+	let end = start + lines[start..].iter()
+		.take_while(|line| line.starts_with(" * ") || line.starts_with('|') || line.is_empty())
+		.count();
+
+	let mut content = lines[..start].join("\n");
+	if !content.is_empty() { content.push('\n'); }
+	content.push_str(&progress_table(registrations, timings));
+	if end < lines.len() {
+		content.push('\n');
+		content.push_str(&lines[end..].join("\n"));
+		content.push('\n');
+	}
+
+	fs::write(README_PATH, content).map_err(|e| Error::io(README_PATH, e))?;
+	Ok(README_PATH)
+}
+
+fn progress_table(
+	registrations: &[Registration],
+	timings: Option<&[BenchResult]>,
+) -> String {
+	let legend = [Status::Solved, Status::Unsatisfying, Status::Hacky].iter()
+		.map(|status| format!(" * {} means {}\n", status.marker(), status.meaning()))
+		.collect::<String>();
+
+	let mut header = vec!["Day", "Solution"];
+	if timings.is_some() { header.push("Time"); }
+	let header = [header.clone(), header].concat();
+
+	let separator = header.iter()
+		.map(|h| format!("{}|", "-".repeat(h.len() + 2)))
+		.collect::<String>();
+
+	let mut table = format!("{legend}\n| {} |\n|{separator}\n", header.join(" | "));
+	for row in 1..=ROWS {
+		let cells = [row, row + ROWS].iter()
+			.flat_map(|&day| day_cells(day, registrations, timings))
+			.collect::<Vec<String>>();
+		table.push_str(&format!("| {} |\n", cells.join(" | ")));
+	}
+
+	table
+}
+
+fn day_cells(
+	day: i32,
+	registrations: &[Registration],
+	timings: Option<&[BenchResult]>,
+) -> Vec<String> {
+	if day > 25 {
+		return vec![String::new(); if timings.is_some() { 3 } else { 2 }];
+	}
+
+	let status = registrations.iter()
+		.find(|r| r.info.day == day)
+		.map(|r| r.status)
+		.unwrap_or([Status::Unimplemented; 2]);
+	let solution = status.iter()
+		.map(|s| s.marker())
+		.collect::<Vec<&str>>()
+		.join(" ");
+
+	let mut cells = vec![format!("{day:02}"), solution.trim().to_string()];
+	if let Some(timings) = timings {
+		let times = (1..=2)
+			.filter_map(|part| timings.iter().find(|t| t.day == day && t.part == part))
+			.filter_map(|t| t.solve.as_ref().ok())
+			.map(|stats| format!("{:.2?}", stats.median))
+			.collect::<Vec<String>>();
+		cells.push(times.join(" / "));
+	}
+
+	cells
+}
@@ -0,0 +1,70 @@
+use std::fs;
+use std::path::Path;
+use aoc2023::error::Error;
+use aoc2023::solution;
+
+const TEMPLATE: &str = include_str!("templates/day.rs.template");
+const SOLUTION_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/solution");
+const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+/// Generates the solution of a new day from the template, adds it to the registry
+/// and creates an empty input file for it. Returns the files that were written.
+pub fn new_day(
+	day: i32,
+	title: &str,
+) -> Result<Vec<String>, Error> {
+	if !(1..=25).contains(&day) {
+		return Err(Error::invalid(format!("Day must be between 1 and 25, got {day}")));
+	}
+	if solution::find(day).is_some() {
+		return Err(Error::invalid(format!("Day {day} already exists")));
+	}
+
+	let module = format!("day{day:02}");
+	let source_path = format!("{SOLUTION_DIR}/{module}.rs");
+	if Path::new(&source_path).exists() {
+		return Err(Error::invalid(format!("{source_path} already exists")));
+	}
+
+	let source = TEMPLATE
+		.replace("$padded", &format!("{day:02}"))
+		.replace("$day", &day.to_string())
+		.replace("$title", &title.replace('\\', "\\\\").replace('"', "\\\""));
+	fs::write(&source_path, source).map_err(|e| Error::io(&source_path, e))?;
+	let mut written = vec![source_path];
+
+	let registry_path = format!("{SOLUTION_DIR}/mod.rs");
+	let registry = fs::read_to_string(&registry_path).map_err(|e| Error::io(&registry_path, e))?;
+	fs::write(&registry_path, register(&registry, &module, day)?).map_err(|e| Error::io(&registry_path, e))?;
+	written.push(registry_path);
+
+	let input_path = format!("{DATA_DIR}/{day:02}");
+	if !Path::new(&input_path).exists() {
+		fs::create_dir_all(DATA_DIR).map_err(|e| Error::io(DATA_DIR, e))?;
+		fs::write(&input_path, "").map_err(|e| Error::io(&input_path, e))?;
+		written.push(input_path);
+	}
+
+	Ok(written)
+}
+
+/// Adds `module::DayNN` to the `register!` list in the source of the solution module, keeping it sorted.
+fn register(
+	registry: &str,
+	module: &str,
+	day: i32,
+) -> Result<String, Error> {
+	let start = registry.find("register! {\n")
+		.map(|i| i + "register! {\n".len())
+		.ok_or(Error::parse("Could not find the register! list in the solution module"))?;
+	let end = start + registry[start..].find("}\n")
+		.ok_or(Error::parse("The register! list in the solution module is not closed"))?;
+
+	let mut entries = registry[start..end].lines()
+		.map(|line| line.to_string())
+		.collect::<Vec<String>>();
+	entries.push(format!("\t{module}::Day{day:02},"));
+	entries.sort();
+
+	Ok(format!("{}{}\n{}", &registry[..start], entries.join("\n"), &registry[end..]))
+}
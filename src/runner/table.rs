@@ -0,0 +1,95 @@
+use aoc2023::error::ErrorKind;
+use crate::runner::{Outcome, RunResult};
+use aoc2023::solution::{Registration, VariantInfo, DEFAULT_VARIANT};
+
+pub fn print_table(results: &[RunResult]) {
+	let rows = results.iter()
+		.map(|result| {
+			let (answer, status) = match &result.outcome {
+				Outcome::Passed(answer) => (answer.to_string(), "passed"),
+				Outcome::Failed(error) if error.kind() == ErrorKind::TimedOut => (String::new(), "timed out"),
+				Outcome::Failed(error) => (error.root().to_string(), "failed"),
+				Outcome::Mismatch { actual, .. } => (actual.to_string(), "mismatch"),
+				Outcome::NotImplemented => (String::new(), "not implemented"),
+			};
+			// Parts that never got to run, e.g. because the input could not be read, have no timings
+			let (parse, solve) = match result.outcome {
+				Outcome::NotImplemented => (String::new(), String::new()),
+				_ if result.duration.is_zero() => (String::new(), String::new()),
+				_ => (format!("{:.2?}", result.parse), format!("{:.2?}", result.duration)),
+			};
+
+			vec![format!("{:02}", result.day), result.part.to_string(), answer, parse, solve, status.to_string()]
+		}).collect::<Vec<Vec<String>>>();
+
+	print_grid(&["Day", "Part", "Answer", "Parse", "Solve", "Status"], &rows);
+
+	let passed = results.iter().filter(|r| matches!(r.outcome, Outcome::Passed(_))).count();
+	let failed = results.iter().filter(|r| matches!(r.outcome, Outcome::Failed(_))).count();
+	let mismatched = results.iter().filter(|r| matches!(r.outcome, Outcome::Mismatch { .. })).count();
+	let missing = results.len() - passed - failed - mismatched;
+	println!("\n{passed} passed, {failed} failed, {mismatched} mismatched, {missing} not implemented");
+}
+
+pub fn print_explanations(results: &[RunResult]) {
+	for result in results.iter().filter(|r| !r.explanations.is_empty()) {
+		println!("\nDay {:02} part {}:", result.day, result.part);
+		for explanation in &result.explanations {
+			println!("  {explanation}");
+		}
+	}
+}
+
+pub fn print_registry(registrations: &[Registration]) {
+	let rows = registrations.iter()
+		.map(|r| vec![format!("{:02}", r.info.day), r.info.year.to_string(), r.info.title.to_string()])
+		.collect::<Vec<Vec<String>>>();
+	print_grid(&["Day", "Year", "Title"], &rows);
+}
+
+/// Prints every day that has other variants of its parts, next to the default ones.
+pub fn print_variants(registrations: &[Registration]) {
+	let mut rows = Vec::new();
+	for registration in registrations {
+		for part in [1, 2] {
+			let variants = registration.variants.iter().filter(|v| v.part == part).collect::<Vec<&VariantInfo>>();
+			if variants.is_empty() { continue; }
+
+			let day = format!("{:02}", registration.info.day);
+			rows.push(vec![day.clone(), part.to_string(), DEFAULT_VARIANT.to_string(), "used unless another variant is picked".to_string()]);
+			for variant in variants {
+				rows.push(vec![day.clone(), part.to_string(), variant.name.to_string(), variant.description.to_string()]);
+			}
+		}
+	}
+	print_grid(&["Day", "Part", "Variant", "Description"], &rows);
+}
+
+/// Prints rows as a pipe-separated table with every column padded to its widest cell.
+pub fn print_grid(
+	header: &[&str],
+	rows: &[Vec<String>],
+) {
+	let mut widths = header.iter().map(|h| h.chars().count()).collect::<Vec<usize>>();
+	for row in rows {
+		for (width, cell) in widths.iter_mut().zip(row) {
+			*width = (*width).max(cell.chars().count());
+		}
+	}
+
+	print_row(&header.iter().map(|h| h.to_string()).collect::<Vec<String>>(), &widths);
+	println!("{}", widths.iter().map(|w| "-".repeat(w + 2)).collect::<Vec<String>>().join("|"));
+	for row in rows {
+		print_row(row, &widths);
+	}
+}
+
+fn print_row(
+	row: &[String],
+	widths: &[usize],
+) {
+	let cells = row.iter().zip(widths)
+		.map(|(cell, width)| format!(" {cell:<width$} "))
+		.collect::<Vec<String>>();
+	println!("{}", cells.join("|").trim_end());
+}
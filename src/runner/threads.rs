@@ -0,0 +1,15 @@
+/// Sets up rayon's global pool with the given number of threads.
+/// With a single thread the parallel days run their work in order, so their output is deterministic.
+pub fn configure_threads(s: &str) -> Result<usize, String> {
+	let threads = s.trim().parse::<usize>()
+		.ok()
+		.filter(|&threads| threads > 0)
+		.ok_or(format!("Invalid thread count: '{s}', expected a number of at least 1"))?;
+
+	rayon::ThreadPoolBuilder::new()
+		.num_threads(threads)
+		.build_global()
+		.map_err(|e| format!("Could not set up {threads} thread(s): {e}"))?;
+
+	Ok(threads)
+}
@@ -0,0 +1,65 @@
+use std::any::Any;
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+use aoc2023::error::Error;
+use aoc2023::solution::{Answer, CancelToken, Context, DynSolution};
+
+/// Parses a time limit such as `30s`, `500ms`, `2m` or `1h`. A bare number is in seconds.
+pub fn parse_timeout(s: &str) -> Result<Duration, String> {
+	let s = s.trim();
+	let split = s.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(s.len());
+	let (amount, unit) = s.split_at(split);
+	let amount = amount.parse::<f64>()
+		.map_err(|_| format!("Invalid timeout: '{s}'"))?;
+
+	let seconds = match unit {
+		"ms" => amount / 1000.0,
+		"" | "s" => amount,
+		"m" => amount * 60.0,
+		"h" => amount * 3600.0,
+		_ => return Err(format!("Invalid timeout unit '{unit}', expected ms, s, m or h")),
+	};
+
+	Duration::try_from_secs_f64(seconds)
+		.ok()
+		.filter(|timeout| !timeout.is_zero())
+		.ok_or(format!("Invalid timeout: '{s}'"))
+}
+
+/// Runs a part on its own thread, cancelling it once it takes longer than `timeout`.
+/// A part that never checks [Context::check_cancelled] keeps its thread busy until the program exits,
+/// but the runner moves on either way.
+pub fn run_with_timeout(
+	part: i32,
+	variant: &str,
+	solution: &Arc<dyn DynSolution>,
+	input: &Arc<dyn Any + Send + Sync>,
+	ctx: Context,
+	timeout: Duration,
+) -> (Result<Answer, Error>, Vec<String>) {
+	let token = CancelToken::new();
+	let ctx = ctx.with_cancel_token(token.clone());
+	let (sender, receiver) = mpsc::channel();
+	let (solution, input, variant) = (Arc::clone(solution), Arc::clone(input), variant.to_string());
+
+	// Same stack size as the main thread, for the solvers that recurse deeply
+	thread::Builder::new()
+		.name(format!("part {part}"))
+		.stack_size(8 * 1024 * 1024)
+		.spawn(move || {
+			let result = solution.part_variant(part, &variant, input.as_ref(), &ctx);
+			// Nobody is listening anymore if the part already timed out
+			let _ = sender.send((result, ctx.into_explanations()));
+		})
+		.expect("failed to start a thread for the part");
+
+	match receiver.recv_timeout(timeout) {
+		Ok(finished) => finished,
+		Err(mpsc::RecvTimeoutError::Timeout) => {
+			token.cancel();
+			(Err(Error::TimedOut), Vec::new())
+		}
+		Err(mpsc::RecvTimeoutError::Disconnected) => (Err(Error::unsolvable("The solver panicked")), Vec::new()),
+	}
+}
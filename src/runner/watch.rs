@@ -0,0 +1,74 @@
+use std::fs;
+use std::thread;
+use std::time::{Duration, SystemTime};
+use aoc2023::solution::{InputSource, Registration};
+use crate::runner::{run_day, Outcome, RunOptions, RunResult};
+
+/// How often the input file is checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Runs the given parts on the input at `path`, and again every time the file changes,
+/// comparing the answers and timings with the previous run. Only stops when the program is killed.
+pub fn watch_day(
+	parts: &[i32],
+	registration: &Registration,
+	path: &str,
+	options: &RunOptions,
+) -> ! {
+	let day = registration.info.day;
+	let mut modified = modified_at(path);
+	let mut previous: Vec<RunResult> = Vec::new();
+
+	loop {
+		let solution = registration.build(InputSource::File(path.to_string()));
+		let results = run_day(day, parts, solution.into(), options);
+		for result in &results {
+			print_run(result, previous.iter().find(|p| p.part == result.part));
+		}
+		println!("\nWatching {path} for changes, press Ctrl-C to stop");
+		previous = results;
+
+		loop {
+			thread::sleep(POLL_INTERVAL);
+			let now = modified_at(path);
+			if now != modified {
+				modified = now;
+				break;
+			}
+		}
+		// Editors tend to write a file in more than one go, give them a moment to finish
+		thread::sleep(POLL_INTERVAL);
+		println!("\n{path} changed, running day {day} again");
+	}
+}
+
+fn modified_at(path: &str) -> Option<SystemTime> {
+	fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+fn print_run(
+	result: &RunResult,
+	previous: Option<&RunResult>,
+) {
+	let answer = match &result.outcome {
+		Outcome::Passed(answer) | Outcome::Mismatch { actual: answer, .. } => answer,
+		Outcome::Failed(error) => return println!("Part {}: Error: {}", result.part, error.root()),
+		Outcome::NotImplemented => return println!("Part {}: not yet implemented", result.part),
+	};
+
+	let mut line = format!("Part {}: {answer} in {:.2?}", result.part, result.duration);
+	if let Some(previous) = previous {
+		let diff = match result.duration.checked_sub(previous.duration) {
+			Some(slower) => format!("+{slower:.2?}"),
+			None => format!("-{:.2?}", previous.duration - result.duration),
+		};
+		line.push_str(&format!(" ({diff})"));
+
+		match &previous.outcome {
+			Outcome::Passed(before) if before == answer => {}
+			Outcome::Passed(before) => line.push_str(&format!(", was {before}")),
+			_ => line.push_str(", did not have an answer before"),
+		}
+	}
+	println!("{line}");
+}
@@ -0,0 +1,45 @@
+use std::fmt::{Display, Formatter};
+
+/// The answer to a part, as it would be typed into the puzzle's answer box.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+	/// A whole number.
+	Integer(i64),
+	/// For answers that don't fit in an `i64`.
+	BigInteger(i128),
+	/// Anything that isn't a number.
+	Text(String),
+}
+
+impl Display for Answer {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Answer::Integer(n) => write!(f, "{n}"),
+			Answer::BigInteger(n) => write!(f, "{n}"),
+			Answer::Text(s) => write!(f, "{s}"),
+		}
+	}
+}
+
+macro_rules! from_integer {
+	($($t:ty),*) => {
+		$(impl From<$t> for Answer {
+			fn from(n: $t) -> Self {
+				match i64::try_from(n) {
+					Ok(n) => Answer::Integer(n),
+					Err(_) => Answer::BigInteger(n as i128),
+				}
+			}
+		})*
+	};
+}
+
+from_integer!(i32, u32, i64, u64, usize, i128);
+
+impl From<String> for Answer {
+	fn from(s: String) -> Self { Answer::Text(s) }
+}
+
+impl From<&str> for Answer {
+	fn from(s: &str) -> Self { Answer::Text(s.to_string()) }
+}
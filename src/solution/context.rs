@@ -0,0 +1,106 @@
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use crate::error::Error;
+use crate::solution::{Params, Progress};
+use crate::util::Render;
+
+/// Everything a part gets from the runner besides its input.
+#[derive(Debug, Default)]
+pub struct Context {
+	explanations: Mutex<Vec<String>>,
+	params: Params,
+	cancelled: CancelToken,
+	show_progress: bool,
+	show_renders: bool,
+}
+
+/// A flag shared between the runner and a running part, set when the part should stop.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+	/// A token that has not been cancelled yet.
+	pub fn new() -> CancelToken { CancelToken::default() }
+
+	/// Asks every part holding this token to stop.
+	pub fn cancel(&self) { self.0.store(true, Ordering::Relaxed) }
+
+	/// Whether [CancelToken::cancel] has been called.
+	pub fn is_cancelled(&self) -> bool { self.0.load(Ordering::Relaxed) }
+}
+
+impl Context {
+	/// A context without any explanations yet.
+	pub fn new() -> Context { Context::default() }
+
+	/// A context that hands `params` to the solution.
+	pub fn with_params(params: Params) -> Context {
+		Context { params, ..Context::default() }
+	}
+
+	/// The same context, cancelled through `token` instead of its own token.
+	pub fn with_cancel_token(self, token: CancelToken) -> Context {
+		Context { cancelled: token, ..self }
+	}
+
+	/// The same context, drawing the bars of [Context::progress] if `show` is set.
+	pub fn with_progress(self, show: bool) -> Context {
+		Context { show_progress: show, ..self }
+	}
+
+	/// The same context, printing the pictures of [Context::render] if `show` is set.
+	pub fn with_renders(self, show: bool) -> Context {
+		Context { show_renders: show, ..self }
+	}
+
+	/// Fails with [Error::TimedOut] once the runner has cancelled the part.
+	/// Long running loops should call this regularly, so they can actually be stopped.
+	pub fn check_cancelled(&self) -> Result<(), Error> {
+		if self.cancelled.is_cancelled() { Err(Error::TimedOut) } else { Ok(()) }
+	}
+
+	/// The value of one of the parameters the solution declared in [crate::solution::Solution::params].
+	pub fn param(&self, name: &str) -> Result<usize, Error> {
+		self.params.get(name)
+	}
+
+	/// Starts tracking `total` units of work, see [Progress].
+	pub fn progress(
+		&self,
+		label: &str,
+		total: usize,
+	) -> Progress {
+		Progress::new(label, total, self.show_progress)
+	}
+
+	/// Prints a picture of the state of the part to stderr, in colour when stderr is a terminal.
+	/// The runner only asks for it with `--render`, `picture` is not even drawn otherwise.
+	pub fn render(
+		&self,
+		title: &str,
+		picture: impl FnOnce() -> Render,
+	) {
+		if !self.show_renders { return; }
+
+		let stderr = std::io::stderr();
+		let picture = picture().ansi(stderr.is_terminal());
+		eprintln!("{title}:\n{picture}");
+	}
+
+	/// Adds human readable commentary on how the answer was found.
+	/// The runner only prints it when asked to with `--explain`.
+	pub fn explain<S: Into<String>>(
+		&self,
+		explanation: S,
+	) {
+		if let Ok(mut explanations) = self.explanations.lock() {
+			explanations.push(explanation.into());
+		}
+	}
+
+	/// Everything passed to [Context::explain], in order.
+	pub fn into_explanations(self) -> Vec<String> {
+		self.explanations.into_inner().unwrap_or_default()
+	}
+}
@@ -1,19 +1,62 @@
 use regex::{Match, Regex};
-use crate::solution::Solution;
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Solution, Status};
 
+/// Day 1: Trebuchet?!
 pub struct Day01 {
-	file: String,
+	source: InputSource,
 }
 
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+",
+		part_one: Some("142"),
+		part_two: None,
+		params: &[],
+	},
+	Example {
+		input: "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+",
+		part_one: None,
+		part_two: Some("281"),
+		params: &[],
+	},
+];
+
 impl Solution for Day01 {
-	fn new(file: &str) -> Self { Day01 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
+	type Input = Vec<String>;
+
+	fn new(source: InputSource) -> Self { Day01 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 1, year: 2023, title: "Trebuchet?!" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		Ok(input.lines().map(|line| line.to_string()).collect())
+	}
 
-	fn part_one(&self) -> Result<String, String> {
-		let result = self.read_file_as_string()?.lines()
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		let result = input.iter()
 			.filter_map(|line| {
 				let digits = line.chars()
-					.filter(|c| c.is_digit(10))
+					.filter(|c| c.is_ascii_digit())
 					.collect::<Vec<char>>();
 
 				digits.first()
@@ -22,16 +65,18 @@ impl Solution for Day01 {
 					.and_then(|s| s.parse::<u32>().ok())
 			}).sum::<u32>();
 
-		Ok(result.to_string())
+		Ok(result.into())
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		let regex = match Regex::new(r"^(\d|one|two|three|four|five|six|seven|eight|nine)") {
-			Ok(r) => Ok(r),
-			Err(_) => Err("Failed to create regex"),
-		}?;
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		let regex = Regex::new(r"^(\d|one|two|three|four|five|six|seven|eight|nine)")
+			.map_err(|e| Error::unsolvable(format!("Failed to create regex: {e}")))?;
 
-		let result = self.read_file_as_string()?.lines()
+		let result = input.iter()
 			.map(|s| s.to_lowercase()).filter_map(|line| {
 			let numbers = (0..line.len()).filter_map(|start| {
 				let slice = line.chars().skip(start).collect::<String>();
@@ -44,7 +89,7 @@ impl Solution for Day01 {
 				.and_then(|s| s.parse::<u32>().ok())
 		}).sum::<u32>();
 
-		Ok(result.to_string())
+		Ok(result.into())
 	}
 }
 
@@ -64,3 +109,10 @@ fn parse_line(
 		c => Err(format!("Invalid number: {c}")),
 	}.ok()
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day01: part_one, part_two);
+}
@@ -1,7 +1,9 @@
-use crate::solution::Solution;
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Param, Solution, Status};
 
-pub struct Day02 { file: String }
-struct Game { id: usize, reveals: Vec<ColorCount> }
+/// Day 2: Cube Conundrum
+pub struct Day02 { source: InputSource }
+pub struct Game { id: usize, reveals: Vec<ColorCount> }
 struct ColorCount { red: usize, green: usize, blue: usize }
 
 impl Game {
@@ -25,48 +27,40 @@ impl ColorCount {
 }
 
 impl Day02 {
-	fn parse_input(&self) -> Result<Vec<Game>, String> {
-		let games: Vec<Game> = self.read_file_as_string()?.lines()
-			.filter_map(|line| self.parse_game(line).ok())
-			.collect();
-		Ok(games)
-	}
-
-	fn parse_game(&self, line: &str) -> Result<Game, String> {
+	fn parse_game(line: &str) -> Result<Game, Error> {
 		let mut split = line.split(": ");
 		let id: usize = split.next()
-			.ok_or("Failed to get game id".to_string())?
+			.ok_or(Error::parse("Failed to get game id"))?
 			.replace("Game ", "")
 			.parse()
-			.map_err(|_| "Failed to parse game id".to_string())?;
+			.map_err(|_| Error::parse("Failed to parse game id"))?;
 
 		let reveals = split.next()
-			.ok_or("Failed to get reveals".to_string())?
+			.ok_or(Error::parse("Failed to get reveals"))?
 			.split("; ")
-			.into_iter()
-			.filter_map(|reveal| self.parse_reveal(reveal).ok())
-			.collect::<Vec<ColorCount>>();
+			.map(Day02::parse_reveal)
+			.collect::<Result<Vec<ColorCount>, Error>>()?;
 
 		Ok(Game { id, reveals })
 	}
 
-	fn parse_reveal(&self, line: &str) -> Result<ColorCount, String> {
+	fn parse_reveal(line: &str) -> Result<ColorCount, Error> {
 		let mut red = 0;
 		let mut green = 0;
 		let mut blue = 0;
 
 		for part in line.split(", ") {
-			let mut subs = part.split(" ");
+			let mut subs = part.split(' ');
 			let count = subs.next()
-				.ok_or(format!("Failed to get count from part {part}"))?
+				.ok_or(Error::parse(format!("Failed to get count from part {part}")))?
 				.parse::<usize>()
-				.or_else(|_| Err("Failed to parse count".to_string()))?;
+				.map_err(|_| Error::parse(format!("Failed to parse count from part {part}")))?;
 
 			match subs.next() {
 				Some("blue") => blue += count,
 				Some("red") => red += count,
 				Some("green") => green += count,
-				_ => return Err(format!("Failed to parse color from part {part}")),
+				_ => return Err(Error::parse(format!("Failed to parse color from part {part}"))),
 			};
 		}
 
@@ -74,22 +68,72 @@ impl Day02 {
 	}
 }
 
+const PARAMS: &[Param] = &[
+	Param { name: "red", description: "red cubes in the bag", default: 12, min: 0 },
+	Param { name: "green", description: "green cubes in the bag", default: 13, min: 0 },
+	Param { name: "blue", description: "blue cubes in the bag", default: 14, min: 0 },
+];
+
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+",
+		part_one: Some("8"),
+		part_two: Some("2286"),
+		params: &[],
+	},
+];
+
 impl Solution for Day02 {
-	fn new(file: &str) -> Self { Day02 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
+	type Input = Vec<Game>;
+
+	fn new(source: InputSource) -> Self { Day02 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 2, year: 2023, title: "Cube Conundrum" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+	fn params() -> &'static [Param] { PARAMS }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		input.lines()
+			.enumerate()
+			.map(|(i, line)| Day02::parse_game(line).map_err(|e| e.at_line(i + 1)))
+			.collect()
+	}
 
-	fn part_one(&self) -> Result<String, String> {
-		let cube_count = ColorCount { blue: 14, red: 12, green: 13 };
-		let result = self.parse_input()?.iter()
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		let cube_count = ColorCount {
+			blue: ctx.param("blue")?,
+			red: ctx.param("red")?,
+			green: ctx.param("green")?,
+		};
+		let possible = input.iter()
 			.filter(|game| game.is_possible(&cube_count))
+			.collect::<Vec<&Game>>();
+		let result = possible.iter()
 			.map(|game| game.id)
 			.reduce(|a, b| a + b)
-			.ok_or("Failed to sum game ids".to_string())?;
-		Ok(format!("Sum of possible game ids: {result}"))
+			.ok_or(Error::invalid("No games to sum"))?;
+
+		ctx.explain(format!("{} out of {} games are possible", possible.len(), input.len()));
+		Ok(result.into())
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		let result = self.parse_input()?.iter().map(|game| {
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		let result = input.iter().map(|game| {
 			let mut red = 0;
 			let mut green = 0;
 			let mut blue = 0;
@@ -100,8 +144,16 @@ impl Solution for Day02 {
 			}
 
 			red * green * blue
-		}).reduce(|a, b| a + b).ok_or("Failed to sum game powers".to_string())?;
+		}).reduce(|a, b| a + b).ok_or(Error::invalid("No games to sum"))?;
 
-		Ok(format!("Sum of game powers: {result}"))
+		ctx.explain(format!("Sum of the powers of {} games", input.len()));
+		Ok(result.into())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day02: part_one, part_two);
+}
@@ -1,37 +1,17 @@
 use std::collections::HashMap;
-use crate::solution::Solution;
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Solution, Status};
+use crate::util::Point2DExt;
 
+/// Day 3: Gear Ratios
 pub struct Day03 {
-	file: String,
+	source: InputSource,
 }
 
 type CharMap = HashMap<(usize, usize), char>;
 type Point = (usize, usize);
 
 impl Day03 {
-	fn parse_input(&self) -> Result<(CharMap, CharMap), String> {
-		let mut char_map = HashMap::new();
-		let mut num_map = HashMap::new();
-		let mut x: usize = 0;
-		let mut y: usize = 0;
-
-		self.read_file_as_string()?.lines()
-			.for_each(|line| {
-				line.chars().for_each(|char| {
-					if char.is_digit(10) {
-						num_map.insert((x, y), char);
-					} else if char != '.' {
-						char_map.insert((x, y), char);
-					}
-					x += 1;
-				});
-				y += 1;
-				x = 0;
-			});
-
-		Ok((char_map, num_map))
-	}
-
 	fn get_horizontal_number(
 		&self,
 		point: &Point,
@@ -61,55 +41,117 @@ impl Day03 {
 		Some(((x, y), number))
 	}
 
-	fn get_neighbors(
-		&self,
-		point: &Point,
-	) -> Vec<Point> {
-		let (x, y) = point;
-		vec![
-			(x - 1, y - 1), (x + 0, y - 1), (x + 1, y - 1),
-			(x - 1, y + 0), (x + 1, y + 0),
-			(x - 1, y + 1), (x + 0, y + 1), (x + 1, y + 1),
-		]
-	}
 }
 
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+",
+		part_one: Some("4361"),
+		part_two: Some("467835"),
+		params: &[],
+	},
+	// Symbols on the top and left edge have no neighbours above and left of them
+	Example {
+		input: "\
+*12
+3..
+",
+		part_one: Some("15"),
+		part_two: Some("36"),
+		params: &[],
+	},
+];
+
 impl Solution for Day03 {
-	fn new(file: &str) -> Self { Day03 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
+	type Input = (CharMap, CharMap);
+
+	fn new(source: InputSource) -> Self { Day03 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 3, year: 2023, title: "Gear Ratios" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
 
-	fn part_one(&self) -> Result<String, String> {
-		let (char_map, num_map) = self.parse_input()?;
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		let mut char_map = HashMap::new();
+		let mut num_map = HashMap::new();
+		let mut x: usize = 0;
+		let mut y: usize = 0;
+
+		input.lines()
+			.for_each(|line| {
+				line.chars().for_each(|char| {
+					if char.is_ascii_digit() {
+						num_map.insert((x, y), char);
+					} else if char != '.' {
+						char_map.insert((x, y), char);
+					}
+					x += 1;
+				});
+				y += 1;
+				x = 0;
+			});
+
+		Ok((char_map, num_map))
+	}
+
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		let (char_map, num_map) = input;
 		let mut result_map = HashMap::new();
 
 		char_map.keys()
 			.for_each(|point| {
-				self.get_neighbors(point).iter().for_each(|point| {
-					self.get_horizontal_number(point, &num_map)
+				point.surrounding().for_each(|point| {
+					self.get_horizontal_number(&point, num_map)
 						.and_then(|(p, v)| result_map.insert(p, v))
 					;
 				});
 			});
 
-		Ok(result_map.values().sum::<usize>().to_string())
+		Ok(result_map.values().sum::<usize>().into())
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		let (char_map, num_map) = self.parse_input()?;
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		let (char_map, num_map) = input;
 		let mut sum = 0;
 
 		char_map.iter()
 			.filter(|(_, &c)| { c == '*' })
 			.for_each(|(point, _)| {
 				let mut result_map = HashMap::new();
-				self.get_neighbors(point).iter().for_each(|point| {
-					self.get_horizontal_number(point, &num_map)
+				point.surrounding().for_each(|point| {
+					self.get_horizontal_number(&point, num_map)
 						.and_then(|(p, v)| result_map.insert(p, v));
 				});
 
 				if result_map.len() == 2 { sum += result_map.values().product::<usize>(); }
 			});
 
-		Ok(sum.to_string())
+		Ok(sum.into())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day03: part_one, part_two);
+}
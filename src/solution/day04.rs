@@ -1,52 +1,85 @@
 use std::collections::HashSet;
-use crate::solution::Solution;
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Solution, Status};
 use crate::solution::solution::extract_numbers;
 
+/// Day 4: Scratchcards
 pub struct Day04 {
-	file: String,
+	source: InputSource,
 }
 
-struct Card {
+pub struct Card {
 	id: usize,
 	winners: HashSet<usize>,
 	tickets: HashSet<usize>,
 }
 
-impl Day04 {
-	fn parse(&self) -> Result<Vec<Card>, String> {
-		let cards: Vec<Card> = self.read_file_as_string()?.lines().filter_map(|line| {
-			let mut card_split = line.split(": ");
-			let card_num = card_split.next()?[5..].trim_start().parse::<usize>().ok()?;
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+",
+		part_one: Some("13"),
+		part_two: Some("30"),
+		params: &[],
+	},
+];
 
-			let mut list_split = card_split.next()?.split("|");
-			let winners_str = list_split.next()?;
-			let tickets_str = list_split.next()?;
+impl Solution for Day04 {
+	type Input = Vec<Card>;
+
+	fn new(source: InputSource) -> Self { Day04 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 4, year: 2023, title: "Scratchcards" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		input.lines().enumerate().map(|(i, line)| {
+			let (card, numbers) = line.split_once(": ")
+				.ok_or(Error::parse("Missing card numbers").at_line(i + 1))?;
+			let card_num = card.strip_prefix("Card")
+				.and_then(|num| num.trim_start().parse::<usize>().ok())
+				.ok_or(Error::parse("Failed to parse card number").at_line(i + 1))?;
 
-			Some(Card {
+			let (winners_str, tickets_str) = numbers.split_once('|')
+				.ok_or(Error::parse("Missing '|' between winning numbers and tickets").at_line(i + 1))?;
+			let winners_offset = card.len() + 2;
+			let tickets_offset = line.len() - tickets_str.len();
+
+			Ok(Card {
 				id: card_num,
-				winners: extract_numbers::<HashSet<usize>, _>(winners_str).ok()?,
-				tickets: extract_numbers::<HashSet<usize>, _>(tickets_str).ok()?,
+				winners: extract_numbers::<HashSet<usize>, _>(winners_str)
+					.map_err(|e| e.offset_column(winners_offset).at_line(i + 1))?,
+				tickets: extract_numbers::<HashSet<usize>, _>(tickets_str)
+					.map_err(|e| e.offset_column(tickets_offset).at_line(i + 1))?,
 			})
-		}).collect();
-
-		Ok(cards)
+		}).collect()
 	}
-}
 
-impl Solution for Day04 {
-	fn new(file: &str) -> Self { Day04 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
-
-	fn part_one(&self) -> Result<String, String> {
-		let result = self.parse()?.iter().filter_map(|card| {
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		let result = input.iter().filter_map(|card| {
 			let wins = card.tickets.intersection(&card.winners).count() as u32;
 			if wins > 0 { Some(2usize.pow(wins - 1)) } else { None }
 		}).sum::<usize>();
-		Ok(result.to_string())
+		Ok(result.into())
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		let cards = self.parse()?;
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		let cards = input;
 		let mut card_count = (0..cards.len()).map(|_| 1).collect::<Vec<usize>>();
 		let mut result = 0;
 		cards.iter().for_each(|card| {
@@ -56,6 +89,13 @@ impl Solution for Day04 {
 			(0..wins).for_each(|i| card_count[card.id + i] += count);
 		});
 
-		Ok(result.to_string())
+		Ok(result.into())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day04: part_one, part_two);
+}
@@ -1,13 +1,15 @@
-use crate::solution::Solution;
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Solution, Status, Variant};
 use rayon::prelude::*;
 use crate::solution::solution::extract_numbers;
 
+/// Day 5: If You Give A Seed A Fertilizer
 pub struct Day05 {
-	file: String,
+	source: InputSource,
 }
 
 #[derive(Debug)]
-struct ParsedInput {
+pub struct ParsedInput {
 	seeds: Vec<u64>,
 	seed_to_soil: Vec<RangeMapping>,
 	soil_to_fertilizer: Vec<RangeMapping>,
@@ -25,6 +27,30 @@ struct RangeMapping {
 	length: u64,
 }
 
+impl ParsedInput {
+	/// Every map, in the order a seed goes through them.
+	fn layers(&self) -> [&[RangeMapping]; 7] {
+		[
+			&self.seed_to_soil,
+			&self.soil_to_fertilizer,
+			&self.fertilizer_to_water,
+			&self.water_to_light,
+			&self.light_to_temperature,
+			&self.temperature_to_humidity,
+			&self.humidity_to_location,
+		]
+	}
+
+	/// The seeds read as `(start, length)` pairs, the way part two sees them.
+	fn seed_ranges(&self) -> Result<Vec<(u64, u64)>, Error> {
+		if self.seeds.len() % 2 != 0 {
+			return Err(Error::invalid("Seeds should come in start and length pairs"));
+		}
+
+		Ok(self.seeds.chunks(2).map(|pair| (pair[0], pair[1])).collect())
+	}
+}
+
 impl RangeMapping {
 	fn map(&self, value: u64) -> Option<u64> {
 		if value < self.source_start { return None; }
@@ -35,20 +61,153 @@ impl RangeMapping {
 }
 
 impl Day05 {
-	fn parse(&self) -> Result<ParsedInput, String> {
-		let file = self.read_file_as_string()?;
-		let mut lines = file.lines();
+	fn map_with_ranges(
+		&self,
+		mappings: &[RangeMapping],
+		value: u64,
+	) -> u64 {
+		mappings.iter().find_map(|mapping| {
+			mapping.map(value)
+		}).unwrap_or(value)
+	}
+
+	fn seed_to_location(
+		&self,
+		input: &ParsedInput,
+		seed: u64,
+	) -> u64 {
+		let soil = self.map_with_ranges(&input.seed_to_soil, seed);
+		let fertilizer = self.map_with_ranges(&input.soil_to_fertilizer, soil);
+		let water = self.map_with_ranges(&input.fertilizer_to_water, fertilizer);
+		let light = self.map_with_ranges(&input.water_to_light, water);
+		let temperature = self.map_with_ranges(&input.light_to_temperature, light);
+		let humidity = self.map_with_ranges(&input.temperature_to_humidity, temperature);
+		self.map_with_ranges(&input.humidity_to_location, humidity)
+	}
+
+	/// Maps every `(start, end)` range of values at once, splitting them where they cross the edge of a mapping.
+	fn map_ranges(
+		&self,
+		mappings: &[RangeMapping],
+		mut ranges: Vec<(u64, u64)>,
+	) -> Vec<(u64, u64)> {
+		let mut mapped = Vec::new();
+		'ranges: while let Some((start, end)) = ranges.pop() {
+			for mapping in mappings {
+				let source_end = mapping.source_start + mapping.length;
+				let overlap_start = start.max(mapping.source_start);
+				let overlap_end = end.min(source_end);
+				if overlap_start >= overlap_end { continue; }
+
+				let destination = mapping.destination_start + overlap_start - mapping.source_start;
+				mapped.push((destination, destination + overlap_end - overlap_start));
+				if start < overlap_start { ranges.push((start, overlap_start)); }
+				if overlap_end < end { ranges.push((overlap_end, end)); }
+				continue 'ranges;
+			}
+
+			mapped.push((start, end));
+		}
+
+		mapped
+	}
+
+	fn part_two_brute_force(
+		&self,
+		input: &ParsedInput,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		let seed_ranges = input.seed_ranges()?;
+
+		// Hope you have a lot of cores. :)
+		// Seeds are handled in chunks, so cancellation and progress stay out of the hot loop
+		const CHUNK: u64 = 1 << 16;
+		let progress = ctx.progress("Seeds", seed_ranges.iter().map(|(_, size)| *size as usize).sum());
+		let min = seed_ranges.par_iter().map(|&(start, size)| {
+			(0..size.div_ceil(CHUNK)).into_par_iter().map(|chunk| {
+				ctx.check_cancelled()?;
+				let seeds = start + chunk * CHUNK..start + ((chunk + 1) * CHUNK).min(size);
+				progress.add((seeds.end - seeds.start) as usize);
+				Ok(seeds.map(|seed| self.seed_to_location(input, seed)).min().unwrap_or(u64::MAX))
+			}).try_reduce(|| u64::MAX, |a, b| Ok(a.min(b)))
+		}).try_reduce(|| u64::MAX, |a, b| Ok(a.min(b)))?;
+		if min == u64::MAX { return Err(Error::invalid("missing seed values!")); }
+
+		Ok(min.into())
+	}
+}
+
+const VARIANTS: &[Variant<Day05>] = &[
+	Variant { part: 2, name: "brute-force", description: "maps every single seed in every range", solve: Day05::part_two_brute_force },
+];
+
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+",
+		part_one: Some("35"),
+		part_two: Some("46"),
+		params: &[],
+	},
+];
+
+impl Solution for Day05 {
+	type Input = ParsedInput;
+
+	fn new(source: InputSource) -> Self { Day05 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 5, year: 2023, title: "If You Give A Seed A Fertilizer" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+	fn variants() -> &'static [Variant<Self>] { VARIANTS }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		let mut lines = input.lines().enumerate();
 
 		let seeds = match lines.next() {
-			None => Err("failed to parse seeds".to_string()),
-			Some(s) => Ok(extract_numbers::<Vec<u64>, _>(s)?)
-		}?;
+			Some((_, line)) if line.starts_with("seeds:") => extract_numbers::<Vec<u64>, _>(&line[6..])
+				.map_err(|e| e.at_line(1).offset_column(6))?,
+			_ => return Err(Error::parse("Expected a list of seeds").at_line(1)),
+		};
 		lines.next(); lines.next(); // skip empty line and first descriptor
 
 		let mut skip_descriptor = false;
 		let mut ranges: Vec<Vec<RangeMapping>> = Vec::new();
 		let mut current_range: Vec<RangeMapping> = Vec::new();
-		for line in lines {
+		for (i, line) in lines {
 			if skip_descriptor { skip_descriptor = false; continue; }
 			if line.is_empty() {
 				ranges.push(current_range);
@@ -57,13 +216,19 @@ impl Day05 {
 				continue;
 			}
 
-			let numbers = extract_numbers::<Vec<u64>, _>(line)?;
+			let numbers = extract_numbers::<Vec<u64>, _>(line).map_err(|e| e.at_line(i + 1))?;
+			if numbers.len() != 3 {
+				return Err(Error::parse(format!("Expected 3 numbers, found {}", numbers.len())).at_line(i + 1));
+			}
 			current_range.push(RangeMapping {
 				destination_start: numbers[0],
 				source_start: numbers[1],
 				length: numbers[2],
 			});
 		}
+		if ranges.len() != 6 {
+			return Err(Error::invalid(format!("Expected 7 maps, found {}", ranges.len() + 1)));
+		}
 
 		Ok(ParsedInput {
 			seeds,
@@ -77,63 +242,43 @@ impl Day05 {
 		})
 	}
 
-	fn map_with_ranges(
-		&self,
-		mappings: &Vec<RangeMapping>,
-		value: u64,
-	) -> u64 {
-		mappings.iter().find_map(|mapping| {
-			mapping.map(value)
-		}).unwrap_or(value)
-	}
-
-	fn seed_to_location(
+	fn part_one(
 		&self,
-		input: &ParsedInput,
-		seed: u64,
-	) -> u64 {
-		let soil = self.map_with_ranges(&input.seed_to_soil, seed);
-		let fertilizer = self.map_with_ranges(&input.soil_to_fertilizer, soil);
-		let water = self.map_with_ranges(&input.fertilizer_to_water, fertilizer);
-		let light = self.map_with_ranges(&input.water_to_light, water);
-		let temperature = self.map_with_ranges(&input.light_to_temperature, light);
-		let humidity = self.map_with_ranges(&input.temperature_to_humidity, temperature);
-		self.map_with_ranges(&input.humidity_to_location, humidity)
-	}
-}
-
-impl Solution for Day05 {
-	fn new(file: &str) -> Self { Day05 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
-
-	fn part_one(&self) -> Result<String, String> {
-		let input = self.parse()?;
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
 		let min: u64 = input.seeds.iter()
-			.map(|seed| self.seed_to_location(&input, *seed))
+			.map(|seed| self.seed_to_location(input, *seed))
 			.min()
-			.ok_or("missing seed values!".to_string())?;
+			.ok_or(Error::invalid("missing seed values!"))?;
 
-		Ok(min.to_string())
+		Ok(min.into())
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		let input = self.parse()?;
-
-		let mut seeds = input.seeds.clone();
-		let mut seed_ranges: Vec<(u64, u64)> = Vec::new();
-		while seeds.len() > 0 {
-			let s1 = seeds.remove(0);
-			let s2 = seeds.remove(0);
-			seed_ranges.push((s1, s2));
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		let mut ranges = input.seed_ranges()?.into_iter()
+			.map(|(start, length)| (start, start + length))
+			.collect::<Vec<(u64, u64)>>();
+		for mappings in input.layers() {
+			ranges = self.map_ranges(mappings, ranges);
 		}
 
-		// Hope you have a lot of cores. :)
-		let min = seed_ranges.par_iter().filter_map(|(start, size)| {
-			(0..*size).into_par_iter().map(|offset| {
-				self.seed_to_location(&input, start + offset)
-			}).min()
-		}).min().ok_or("missing seed values!".to_string())?;
+		let min = ranges.iter()
+			.map(|(start, _)| *start)
+			.min()
+			.ok_or(Error::invalid("missing seed values!"))?;
 
-		Ok(min.to_string())
+		Ok(min.into())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day05: part_one, part_two);
+}
@@ -1,76 +1,125 @@
 use rayon::prelude::*;
-use crate::solution::Solution;
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Solution, Status};
 use crate::solution::solution::extract_numbers;
 
+/// Day 6: Wait For It
 pub struct Day06 {
-	file: String,
+	source: InputSource,
 }
 
 #[derive(Debug)]
-struct TimeDistance {
+pub struct TimeDistance {
 	time: usize,
 	distance: usize,
 }
 
 impl Day06 {
-	fn parse(&self) -> Result<Vec<TimeDistance>, String> {
-		let file = self.read_file_as_string()?;
-		let mut lines = file.lines();
-		let time = extract_numbers::<Vec<usize>, _>(lines.next().ok_or("failed to extract time")?)?;
-		let distance = extract_numbers::<Vec<usize>, _>(lines.next().ok_or("failed to extract time")?)?;
-
-		let tds = time.iter()
-			.zip(distance)
-			.map(|(&time, distance)| TimeDistance { time, distance })
-			.collect::<Vec<TimeDistance>>();
-		Ok(tds)
+	fn parse_line(
+		line: Option<&str>,
+		label: &str,
+	) -> Result<Vec<usize>, Error> {
+		let numbers = line
+			.and_then(|line| line.strip_prefix(label))
+			.ok_or(Error::parse(format!("Expected a line starting with {label:?}")))?;
+		extract_numbers::<Vec<usize>, _>(numbers).map_err(|e| e.offset_column(label.len()))
 	}
 
 	fn merge_time_distances(
 		&self,
-		tds: Vec<TimeDistance>,
-	) -> Result<TimeDistance, String> {
+		tds: &[TimeDistance],
+	) -> Result<TimeDistance, Error> {
 		let time = tds.iter()
 			.map(|td| td.time.to_string())
 			.collect::<String>()
 			.parse::<usize>()
-			.map_err(|_| format!("failed to merge time"))?;
+			.map_err(|_| Error::invalid("Merged time does not fit in a usize"))?;
 		let distance = tds.iter()
 			.map(|td| td.distance.to_string())
 			.collect::<String>()
 			.parse::<usize>()
-			.map_err(|_| format!("failed to merge distance"))?;
+			.map_err(|_| Error::invalid("Merged distance does not fit in a usize"))?;
 
 		Ok(TimeDistance { time, distance })
 	}
 }
 
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+Time:      7  15   30
+Distance:  9  40  200
+",
+		part_one: Some("288"),
+		part_two: Some("71503"),
+		params: &[],
+	},
+];
+
 impl Solution for Day06 {
-	fn new(file: &str) -> Self { Day06 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
+	type Input = Vec<TimeDistance>;
+
+	fn new(source: InputSource) -> Self { Day06 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 6, year: 2023, title: "Wait For It" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		let mut lines = input.lines();
+		let time = Day06::parse_line(lines.next(), "Time:").map_err(|e| e.at_line(1))?;
+		let distance = Day06::parse_line(lines.next(), "Distance:").map_err(|e| e.at_line(2))?;
+		if time.len() != distance.len() {
+			return Err(Error::invalid(format!("Found {} times but {} distances", time.len(), distance.len())));
+		}
 
-	fn part_one(&self) -> Result<String, String> {
-		let input = self.parse()?;
-		let result = input.into_par_iter()
+		let tds = time.iter()
+			.zip(distance)
+			.map(|(&time, distance)| TimeDistance { time, distance })
+			.collect::<Vec<TimeDistance>>();
+		Ok(tds)
+	}
+
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		let result = input.par_iter()
 			.map(|td| {
-				(1..td.time).filter_map(|time_held| {
+				ctx.check_cancelled()?;
+				Ok((1..td.time).filter_map(|time_held| {
 					let travel = (td.time - time_held) * time_held;
-					(travel > td.distance).then(|| (time_held, travel))
-				}).count()
-			}).product::<usize>();
-		Ok(result.to_string())
+					(travel > td.distance).then_some((time_held, travel))
+				}).count())
+			}).try_reduce(|| 1, |a, b| Ok(a * b))?;
+		Ok(result.into())
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		let input = self.parse()?;
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
 		let td = self.merge_time_distances(input)?;
 
-		let result = (1..td.time).into_par_iter()
-			.filter_map(|time_held| {
-				let travel = (td.time - time_held) * time_held;
-				(travel > td.distance).then(|| (time_held, travel))
-			}).count();
+		// Checking for cancellation once per chunk keeps it out of the hot loop
+		const CHUNK: usize = 1 << 16;
+		let result = (0..td.time.div_ceil(CHUNK)).into_par_iter()
+			.map(|chunk| {
+				ctx.check_cancelled()?;
+				let start = (chunk * CHUNK).max(1);
+				let end = ((chunk + 1) * CHUNK).min(td.time);
+				Ok((start..end).filter(|time_held| (td.time - time_held) * time_held > td.distance).count())
+			}).try_reduce(|| 0, |a, b| Ok(a + b))?;
 
-		Ok(result.to_string())
+		Ok(result.into())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day06: part_one, part_two);
+}
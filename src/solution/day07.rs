@@ -1,21 +1,22 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use crate::solution::day07::HandType::*;
-use crate::solution::Solution;
+use crate::debug;
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Solution, Status};
 
+/// Day 7: Camel Cards
 pub struct Day07 {
-	file: String,
+	source: InputSource,
 }
 
-impl Day07 {}
-
 #[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Copy, Clone)]
 enum Card { X2, X3, X4, X5, X6, X7, X8, X9, T, J, Q, K, A }
 
 #[derive(Debug, PartialEq, PartialOrd)]
 enum HandType { HighCard, OnePair, TwoPair, ThreeOfAKind, FullHouse, FourOfAKind, FiveOfAKind }
 
-fn char_to_card(c: char) -> Result<Card, String> {
+fn char_to_card(c: char) -> Result<Card, Error> {
 	match c {
 		'2' => Ok(Card::X2),
 		'3' => Ok(Card::X3),
@@ -30,21 +31,22 @@ fn char_to_card(c: char) -> Result<Card, String> {
 		'Q' => Ok(Card::Q),
 		'K' => Ok(Card::K),
 		'A' => Ok(Card::A),
-		_ => Err(format!("Invalid card: {c}")),
+		_ => Err(Error::parse(format!("Invalid card: {c}"))),
 	}
 }
 
 #[derive(Debug)]
-struct Hand {
+pub struct Hand {
 	cards: Vec<Card>,
 	hand_type: HandType,
+	joker_hand_type: HandType,
 	bid: usize,
 }
 
 fn cards_to_type(
-	cards: &Vec<Card>,
+	cards: &[Card],
 	with_joker: bool,
-) -> Result<HandType, String> {
+) -> Result<HandType, Error> {
 	let mut map = HashMap::<Card, usize>::new();
 	let mut jokers = 0;
 	cards.iter().for_each(|&card| {
@@ -55,7 +57,7 @@ fn cards_to_type(
 		}
 	});
 
-	let mut groupings = map.values().map(|&x| x).collect::<Vec<usize>>();
+	let mut groupings = map.values().copied().collect::<Vec<usize>>();
 	groupings.sort();
 
 	let size = groupings.len();
@@ -75,38 +77,60 @@ fn cards_to_type(
 		[1, 1, 1, 2] => Ok(OnePair),
 		[1, 1, 1, 1, 1] => Ok(HighCard),
 		_ => {
-			println!("{cards:?} => {groupings:?}");
-			Err(format!("Invalid hand: {cards:?}"))
+			debug!("{cards:?} => {groupings:?}");
+			Err(Error::invalid(format!("Invalid hand: {cards:?}")))
 		},
 	}
 }
 
-impl Day07 {
-	fn parse(
-		&self,
-		with_joker: bool,
-	) -> Result<Vec<Hand>, String> {
-		let hand = self.read_file_as_string()?.lines().filter_map(|line| {
-			let mut line_split = line.split(" ");
-			let cards = line_split.next()?.chars()
-				.map(|c| char_to_card(c))
-				.collect::<Result<Vec<Card>, String>>()
-				.ok()?;
-			let bid = line_split.next()?.parse::<usize>().ok()?;
-			let hand_type = cards_to_type(&cards, with_joker).ok()?;
-
-			Some(Hand { cards, hand_type, bid })
-		}).collect::<Vec<Hand>>();
-		Ok(hand)
-	}
-}
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+",
+		part_one: Some("6440"),
+		part_two: Some("5905"),
+		params: &[],
+	},
+];
 
 impl Solution for Day07 {
-	fn new(file: &str) -> Self { Day07 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
+	type Input = Vec<Hand>;
+
+	fn new(source: InputSource) -> Self { Day07 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 7, year: 2023, title: "Camel Cards" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		input.lines().enumerate().map(|(i, line)| {
+			let mut line_split = line.split(' ');
+			let cards = line_split.next().unwrap_or_default().chars()
+				.enumerate()
+				.map(|(column, c)| char_to_card(c).map_err(|e| e.at_column(column + 1)))
+				.collect::<Result<Vec<Card>, Error>>()
+				.map_err(|e| e.at_line(i + 1))?;
+			let bid = line_split.next()
+				.and_then(|bid| bid.parse::<usize>().ok())
+				.ok_or(Error::parse("Failed to parse bid").at_line(i + 1))?;
+			let hand_type = cards_to_type(&cards, false).map_err(|e| e.at_line(i + 1))?;
+			let joker_hand_type = cards_to_type(&cards, true).map_err(|e| e.at_line(i + 1))?;
+
+			Ok(Hand { cards, hand_type, joker_hand_type, bid })
+		}).collect()
+	}
 
-	fn part_one(&self) -> Result<String, String> {
-		let mut hands = self.parse(false)?;
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		let mut hands = input.iter().collect::<Vec<&Hand>>();
 
 		hands.sort_by(|a, b| {
 			if a.hand_type > b.hand_type {
@@ -126,22 +150,25 @@ impl Solution for Day07 {
 		for (i, hand) in hands.iter().enumerate() {
 			out += hand.bid * (i + 1);
 		}
-		Ok(out.to_string())
+		Ok(out.into())
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		let mut hands = self.parse(true)?;
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		let mut hands = input.iter().collect::<Vec<&Hand>>();
 
 		hands.sort_by(|a, b| {
-			if a.hand_type > b.hand_type {
+			if a.joker_hand_type > b.joker_hand_type {
 				Ordering::Greater
-			} else if a.hand_type < b.hand_type {
+			} else if a.joker_hand_type < b.joker_hand_type {
 				Ordering::Less
 			} else {
 				a.cards.iter().zip(&b.cards).filter_map(|(&a, &b)| {
 					if a != b && a == Card::J { Some(Ordering::Less) }
-					else if a != b && b == Card::J { Some(Ordering::Greater) }
-					else if a > b { Some(Ordering::Greater) }
+					else if (a != b && b == Card::J) || a > b { Some(Ordering::Greater) }
 					else if a < b { Some(Ordering::Less) }
 					else { None }
 				}).next().unwrap_or(Ordering::Equal)
@@ -152,6 +179,13 @@ impl Solution for Day07 {
 		for (i, hand) in hands.iter().enumerate() {
 			out += hand.bid * (i + 1);
 		}
-		Ok(out.to_string())
+		Ok(out.into())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day07: part_one, part_two);
+}
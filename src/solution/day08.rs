@@ -1,87 +1,146 @@
 use std::collections::{HashMap, HashSet};
 use RelativeDirection::{Left, Right};
-use crate::solution::Solution;
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Solution, Status};
 use crate::util::RelativeDirection;
 
+/// Day 8: Haunted Wasteland
 pub struct Day08 {
-	file: String,
+	source: InputSource,
 }
 
 #[derive(Debug)]
-struct InputData {
+pub struct InputData {
 	directions: Vec<RelativeDirection>,
 	map: HashMap<String, (String, String)>,
 	last_chars: HashMap<String, char>,
 }
 
-impl Day08 {
-	fn parse(&self) -> Result<InputData, String> {
-		let input = self.read_file_as_string()?;
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+",
+		part_one: Some("2"),
+		part_two: None,
+		params: &[],
+	},
+	Example {
+		input: "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+",
+		part_one: Some("6"),
+		part_two: None,
+		params: &[],
+	},
+	Example {
+		input: "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+",
+		part_one: None,
+		part_two: Some("6"),
+		params: &[],
+	},
+];
+
+impl Solution for Day08 {
+	type Input = InputData;
+
+	fn new(source: InputSource) -> Self { Day08 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 8, year: 2023, title: "Haunted Wasteland" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
 		let mut lines = input.lines();
 		let mut directions = Vec::new();
 		let mut map = HashMap::new();
 		let mut last_chars = HashMap::new();
 
-		let rl_chars = lines.next().ok_or("failed to extract right/left chars")?.chars();
-		for c in rl_chars {
+		let rl_chars = lines.next().ok_or(Error::parse("failed to extract right/left chars").at_line(1))?.chars();
+		for (column, c) in rl_chars.enumerate() {
 			match c {
 				'R' => directions.push(Right),
 				'L' => directions.push(Left),
-				_ => return Err(format!("invalid direction: {c}")),
+				_ => return Err(Error::parse(format!("invalid direction: {c}")).at_line(1).at_column(column + 1)),
 			}
 		}
+		if directions.is_empty() {
+			return Err(Error::parse("No right/left directions").at_line(1));
+		}
 		lines.next(); // drop empty line
 
-		for line in lines {
-			let mut eq_split = line.split(" = ");
-			let position = eq_split.next()
-				.ok_or(format!("failed to extract position split from {line}"))?
-				.to_string();
-
-			let lr_part = eq_split.next()
-				.ok_or(format!("failed to extract right/left split from {line}"))?;
-			let lr_split = lr_part.split(", ").collect::<Vec<&str>>();
+		for (i, line) in lines.enumerate() {
+			let line_number = i + 3;
+			let (position, lr_part) = line.split_once(" = ")
+				.ok_or(Error::parse(format!("failed to extract position split from {line}")).at_line(line_number))?;
 
-			let left = lr_split[0][1..].to_string();
-			let right = lr_split[1][0..3].to_string();
+			let (left, right) = lr_part.strip_prefix('(')
+				.and_then(|lr| lr.strip_suffix(')'))
+				.and_then(|lr| lr.split_once(", "))
+				.ok_or(Error::parse(format!("failed to extract right/left split from {line}")).at_line(line_number))?;
 
-			map.insert(position, (left, right));
+			map.insert(position.to_string(), (left.to_string(), right.to_string()));
 		}
 
 		for position in map.keys() {
 			let last_char = position.chars().last()
-				.ok_or(format!("failed to extract last char from {position}"))?;
+				.ok_or(Error::parse(format!("failed to extract last char from {position}")))?;
 			last_chars.insert(position.to_string(), last_char);
 		}
 
 		Ok(InputData { directions, map, last_chars })
 	}
-}
-
-impl Solution for Day08 {
-	fn new(file: &str) -> Self { Day08 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
 
-	fn part_one(&self) -> Result<String, String> {
-		let input = self.parse()?;
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
 		let mut steps = 0;
 		let mut position = "AAA";
 		for direction in input.directions.iter().cycle() {
 			steps += 1;
-			let (left, right) = &input.map[position];
+			let (left, right) = input.map.get(position)
+				.ok_or(Error::invalid(format!("No node named {position}")))?;
 			match direction {
-				Right => position = &right,
-				Left => position = &left,
+				Right => position = right,
+				Left => position = left,
 			}
 
 			if position == "ZZZ" { break; }
 		}
 
-		Ok(steps.to_string())
+		Ok(steps.into())
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		let input = self.parse()?;
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
 		let positions = input.last_chars.iter()
 			.filter(|(_, &last_char)| last_char == 'A')
 			.map(|(position, _)| position.clone())
@@ -97,13 +156,16 @@ impl Solution for Day08 {
 			loop {
 				for direction in input.directions.iter() {
 					steps += 1;
-					let (left, right) = &input.map[&curr];
+					let (left, right) = input.map.get(&curr)
+						.ok_or(Error::invalid(format!("No node named {curr}")))?;
 					curr = match direction {
 						Right => right.clone(),
 						Left => left.clone(),
 					};
 
-					if input.last_chars[&curr] == 'Z' { winners.push(steps) }
+					let last_char = input.last_chars.get(&curr)
+						.ok_or(Error::invalid(format!("No node named {curr}")))?;
+					if *last_char == 'Z' { winners.push(steps) }
 					path.push(curr.clone());
 				}
 
@@ -111,20 +173,23 @@ impl Solution for Day08 {
 				end_positions.insert(curr.clone());
 			}
 
-			let first_occ = path.iter().position(|p| p == &curr).unwrap();
+			// The first end position inside the loop is reached again every time the loop comes around
+			let first_occ = path.iter().position(|p| p == &curr)
+				.ok_or(Error::unsolvable(format!("The path from {start} never comes back to {curr}")))?;
 			winners.iter()
 				.filter(|&w| *w > first_occ)
-				.map(|&x| x)
-				.collect::<Vec<usize>>()
+				.min()
+				.copied()
+				.ok_or(Error::unsolvable(format!("The path from {start} never loops through an end position")))
 		});
 
-		// doesn't work for test input, but works for real input lol
-		let lcm_result = paths.clone().flatten()
+		let lcm_result = paths
+			.collect::<Result<Vec<usize>, Error>>()?
 			.into_iter()
-			.reduce(|a, b| lcm(a, b))
-			.unwrap();
+			.reduce(lcm)
+			.ok_or(Error::invalid("No starting positions"))?;
 
-		Ok(lcm_result.to_string())
+		Ok(lcm_result.into())
 	}
 }
 
@@ -139,3 +204,10 @@ fn gcd(a: usize, b: usize) -> usize {
 fn lcm(a: usize, b: usize) -> usize {
 	a * b / gcd(a, b)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day08: part_one, part_two);
+}
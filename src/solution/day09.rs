@@ -1,23 +1,15 @@
-use crate::solution::Solution;
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Solution, Status};
 use crate::solution::solution::extract_numbers;
 
+/// Day 9: Mirage Maintenance
 pub struct Day09 {
-	file: String,
+	source: InputSource,
 }
 
 type ISand = i32;
 type History = Vec<ISand>;
 
-impl Day09 {
-	fn parse_input(&self) -> Result<Vec<History>, String> {
-		let lines = self.read_file_as_string()?
-			.lines()
-			.map(|line| extract_numbers::<History, _>(line))
-			.collect::<Result<Vec<History>, String>>()?;
-		Ok(lines)
-	}
-}
-
 fn extrapolate_history(
 	history: History,
 ) -> Vec<History> {
@@ -40,7 +32,7 @@ fn extrapolate_history(
 }
 
 fn add_future_predictions(
-	histories: &mut Vec<History>,
+	histories: &mut [History],
 ) {
 	for i in 0..histories.len() {
 		if i == 0 {
@@ -56,7 +48,7 @@ fn add_future_predictions(
 }
 
 fn add_historical_predictions(
-	histories: &mut Vec<History>,
+	histories: &mut [History],
 ) {
 	let len = histories.len();
 
@@ -75,33 +67,76 @@ fn add_historical_predictions(
 	}
 }
 
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+",
+		part_one: Some("114"),
+		part_two: Some("2"),
+		params: &[],
+	},
+];
+
 impl Solution for Day09 {
-	fn new(file: &str) -> Self { Day09 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
+	type Input = Vec<History>;
+
+	fn new(source: InputSource) -> Self { Day09 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 9, year: 2023, title: "Mirage Maintenance" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		input.lines()
+			.enumerate()
+			.map(|(i, line)| match extract_numbers::<History, _>(line) {
+				Ok(history) if history.is_empty() => Err(Error::parse("Expected at least one number").at_line(i + 1)),
+				result => result.map_err(|e| e.at_line(i + 1)),
+			})
+			.collect()
+	}
 
-	fn part_one(&self) -> Result<String, String> {
-		let result = self.parse_input()?.iter()
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		let result = input.iter()
 			.map(|history| {
 				let mut new_history = extrapolate_history(history.clone());
 				add_future_predictions(&mut new_history);
 				*new_history.last()
-					.map_or(None, |h| h.last())
+					.and_then(|h| h.last())
 					.unwrap_or(&0)
 			}).sum::<ISand>();
 
-		Ok(result.to_string())
+		Ok(result.into())
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		let result = self.parse_input()?.iter()
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		let result = input.iter()
 			.map(|history| {
 				let mut new_history = extrapolate_history(history.clone());
 				add_historical_predictions(&mut new_history);
 				*new_history.last()
-					.map_or(None, |h| h.first())
+					.and_then(|h| h.first())
 					.unwrap_or(&0)
 			}).sum::<ISand>();
 
-		Ok(result.to_string())
+		Ok(result.into())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day09: part_one, part_two);
+}
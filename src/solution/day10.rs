@@ -1,10 +1,12 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 use Direction::{East, North, South, West};
-use crate::solution::Solution;
-use crate::util::{Direction, Point2D, Point2DExt};
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Solution, Status};
+use crate::util::{Cell, Colour, Direction, Grid, Point2D, Point2DExt};
 
+/// Day 10: Pipe Maze
 pub struct Day10 {
-	file: String,
+	source: InputSource,
 }
 
 type Map = HashMap<Point2D, HashSet<Point2D>>;
@@ -29,60 +31,162 @@ fn connect(
 	max_y: usize,
 ) {
 	if let Some(target) = target.filter(|p| p.in_grid(max_x, max_y)) {
-		map.entry(origin).or_insert(HashSet::new()).insert(target);
+		map.entry(origin).or_default().insert(target);
 	}
 }
 
-struct MapInfo {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pipe {
+	/// `|`
+	Vertical,
+	/// `-`
+	Horizontal,
+	/// `L`
+	NorthEast,
+	/// `J`
+	NorthWest,
+	/// `7`
+	SouthWest,
+	/// `F`
+	SouthEast,
+	/// `S`, a pipe of unknown shape
+	Start,
+	/// `.`
+	Ground,
+}
+
+impl Cell for Pipe {
+	fn from_char(c: char) -> Option<Self> {
+		match c {
+			'|' => Some(Pipe::Vertical),
+			'-' => Some(Pipe::Horizontal),
+			'L' => Some(Pipe::NorthEast),
+			'J' => Some(Pipe::NorthWest),
+			'7' => Some(Pipe::SouthWest),
+			'F' => Some(Pipe::SouthEast),
+			'S' => Some(Pipe::Start),
+			'.' => Some(Pipe::Ground),
+			_ => None,
+		}
+	}
+
+	fn to_char(&self) -> char {
+		match self {
+			Pipe::Vertical => '|',
+			Pipe::Horizontal => '-',
+			Pipe::NorthEast => 'L',
+			Pipe::NorthWest => 'J',
+			Pipe::SouthWest => '7',
+			Pipe::SouthEast => 'F',
+			Pipe::Start => 'S',
+			Pipe::Ground => '.',
+		}
+	}
+}
+
+pub struct MapInfo {
 	map: Map,
-	grid: Vec<Vec<char>>,
+	grid: Grid<Pipe>,
 	start: Point2D,
-	max_x: usize,
-	max_y: usize,
 }
 
 impl MapInfo {
-	fn get_char(&self, point: Option<Point2D>) -> Option<char> {
-		if let Some(p) = point {
-			if p.in_grid(self.max_x, self.max_y) {
-				Some(self.grid[p.y()][p.x()])
-			} else {
-				None
-			}
-		} else {
-			None
+	fn get_pipe(&self, point: Option<Point2D>) -> Option<Pipe> {
+		self.grid.get_point(point?).copied()
+	}
+
+	/// The shape of the pipe under `S`, from the neighbours that connect back to it.
+	fn start_pipe(&self) -> Result<Pipe, Error> {
+		let start = self.start;
+		let connects = |neighbour: Option<Point2D>| neighbour
+			.is_some_and(|n| self.map.get(&start).is_some_and(|connections| connections.contains(&n)));
+
+		match (connects(start.north()), connects(start.south()), connects(start.east()), connects(start.west())) {
+			(true, true, false, false) => Ok(Pipe::Vertical),
+			(false, false, true, true) => Ok(Pipe::Horizontal),
+			(true, false, true, false) => Ok(Pipe::NorthEast),
+			(true, false, false, true) => Ok(Pipe::NorthWest),
+			(false, true, false, true) => Ok(Pipe::SouthWest),
+			(false, true, true, false) => Ok(Pipe::SouthEast),
+			_ => Err(Error::unsolvable(format!("S at {start:?} does not connect to exactly two pipes"))),
 		}
 	}
 }
 
-impl Day10 {
-	fn parse(&self) -> Result<MapInfo, String> {
-		let mut option_start = None;
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+",
+		part_one: Some("4"),
+		part_two: None,
+		params: &[],
+	},
+	Example {
+		input: "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+",
+		part_one: Some("8"),
+		part_two: None,
+		params: &[],
+	},
+	Example {
+		input: "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+",
+		part_one: None,
+		part_two: Some("4"),
+		params: &[],
+	},
+];
+
+impl Solution for Day10 {
+	type Input = MapInfo;
+
+	fn new(source: InputSource) -> Self { Day10 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 10, year: 2023, title: "Pipe Maze" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
 		let mut unfiltered_map = HashMap::new();
-		let grid: Vec<Vec<char>> = self.read_file_as_string()?.lines()
-			.map(|line| line.chars().collect())
-			.collect();
-		let max_x = grid[0].len() - 1;
-		let max_y = grid.len() - 1;
-
-		for y in 0..=max_y {
-			for x in 0..=max_x {
-				let here: Point2D = (x, y);
-				match grid[y][x] {
-					'|' => multi_connect(&mut unfiltered_map, here, here.north(), here.south(), max_x, max_y),
-					'-' => multi_connect(&mut unfiltered_map, here, here.east(), here.west(), max_x, max_y),
-					'L' => multi_connect(&mut unfiltered_map, here, here.north(), here.east(), max_x, max_y),
-					'J' => multi_connect(&mut unfiltered_map, here, here.north(), here.west(), max_x, max_y),
-					'7' => multi_connect(&mut unfiltered_map, here, here.south(), here.west(), max_x, max_y),
-					'F' => multi_connect(&mut unfiltered_map, here, here.south(), here.east(), max_x, max_y),
-					'S' => {
-						option_start = Some(here);
-						multi_connect(&mut unfiltered_map, here, here.south(), here.north(), max_x, max_y);
-						multi_connect(&mut unfiltered_map, here, here.east(), here.west(), max_x, max_y);
-					}
-					'.' => (/* ignore ground */),
-					c => println!("Unknown char: {c}"),
+		let (grid, starts) = Grid::<Pipe>::parse_with_markers(input, 'S')?;
+		if grid.width() == 0 {
+			return Err(Error::invalid("The map is empty"));
+		}
+		let max_x = grid.width() - 1;
+		let max_y = grid.height() - 1;
+
+		for (here, pipe) in grid.cells() {
+			match pipe {
+				Pipe::Vertical => multi_connect(&mut unfiltered_map, here, here.north(), here.south(), max_x, max_y),
+				Pipe::Horizontal => multi_connect(&mut unfiltered_map, here, here.east(), here.west(), max_x, max_y),
+				Pipe::NorthEast => multi_connect(&mut unfiltered_map, here, here.north(), here.east(), max_x, max_y),
+				Pipe::NorthWest => multi_connect(&mut unfiltered_map, here, here.north(), here.west(), max_x, max_y),
+				Pipe::SouthWest => multi_connect(&mut unfiltered_map, here, here.south(), here.west(), max_x, max_y),
+				Pipe::SouthEast => multi_connect(&mut unfiltered_map, here, here.south(), here.east(), max_x, max_y),
+				Pipe::Start => {
+					multi_connect(&mut unfiltered_map, here, here.south(), here.north(), max_x, max_y);
+					multi_connect(&mut unfiltered_map, here, here.east(), here.west(), max_x, max_y);
 				}
+				Pipe::Ground => (/* ignore ground */),
 			}
 		}
 
@@ -100,20 +204,18 @@ impl Day10 {
 			map.insert(*key, new_connections);
 		}
 
-		let start = option_start.ok_or(format!("Could not find starting position"))?;
+		let start = starts.first().copied().ok_or(Error::invalid("Could not find starting position"))?;
 
-		Ok(MapInfo { map, grid, start, max_x, max_y })
+		Ok(MapInfo { map, grid, start })
 	}
-}
 
-impl Solution for Day10 {
-	fn new(file: &str) -> Self { Day10 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
-
-	fn part_one(&self) -> Result<String, String> {
-		let info = self.parse()?;
-		let start = info.start;
-		let map = info.map;
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		let start = input.start;
+		let map = &input.map;
 
 		let mut max = 0;
 		let mut visited = HashSet::new();
@@ -129,7 +231,7 @@ impl Solution for Day10 {
 
 			let next_step = step + 1;
 			for connection in connections {
-				if !visited.contains(&connection) {
+				if !visited.contains(connection) {
 					max = max.max(next_step);
 					queue.push_back((next_step, *connection));
 					visited.insert(*connection);
@@ -137,14 +239,17 @@ impl Solution for Day10 {
 			}
 		}
 
-		Ok(max.to_string())
+		ctx.render("The loop", || input.grid.render().highlight(visited, Colour::Cyan));
+		Ok(max.into())
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		let info2 = self.parse()?;
-		let info = self.parse()?;
-		let start = info.start;
-		let map = info.map;
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		let start = input.start;
+		let map = &input.map;
 
 		let mut visited = HashSet::new();
 		let mut queue = VecDeque::new();
@@ -158,100 +263,100 @@ impl Solution for Day10 {
 			};
 
 			for connection in connections {
-				if !visited.contains(&connection) {
+				if !visited.contains(connection) {
 					queue.push_back(*connection);
 					visited.insert(*connection);
 				}
 			}
 		}
 
+		let start_pipe = input.start_pipe()?;
 		let mut revisited = HashSet::new();
 		let mut side1 = HashSet::new();
 		let mut side2 = HashSet::new();
+		// Whether a side has neighbours outside the map, which makes it the outside
+		let mut side1_off_map = false;
+		let mut side2_off_map = false;
 
 		// Direction we're AT now, and direction we went to get there
 		let mut current = visited.clone().into_iter()
-			.find(|(x, y)| info2.get_char(Some((*x, *y))) == Some('|'))
-			.ok_or(format!("Could not find starting position"))?;
+			.find(|&point| input.get_pipe(Some(point)) == Some(Pipe::Vertical))
+			.ok_or(Error::unsolvable("Could not find a vertical pipe on the loop"))?;
 		let mut direction = North;
 
 		while !revisited.contains(&current) {
-			let curr_char = info2.get_char(Some(current)).unwrap();
+			let curr_pipe = match input.get_pipe(Some(current)).unwrap() {
+				Pipe::Start => start_pipe,
+				pipe => pipe,
+			};
 
-			let (new_side1, new_side2) = match curr_char {
-				'|' => match direction {
+			let (new_side1, new_side2) = match curr_pipe {
+				Pipe::Vertical => match direction {
 					North => (vec![current.west()], vec![current.east()]),
 					South => (vec![current.east()], vec![current.west()]),
-					x => return Err(format!("impossible | ({x:?})")),
+					x => return Err(Error::unsolvable(format!("impossible | ({x:?})"))),
 				}
-				'-' => match direction {
+				Pipe::Horizontal => match direction {
 					East => (vec![current.north()], vec![current.south()]),
 					West => (vec![current.south()], vec![current.north()]),
-					x => return Err(format!("impossible - ({x:?})")),
+					x => return Err(Error::unsolvable(format!("impossible - ({x:?})"))),
 				}
-				'F' => match direction {
+				Pipe::SouthEast => match direction {
 					West => (vec![], vec![current.west(), current.north()]),
 					North => (vec![current.west(), current.north()], vec![]),
-					x => return Err(format!("impossible F ({x:?})")),
+					x => return Err(Error::unsolvable(format!("impossible F ({x:?})"))),
 				}
-				'J' => match direction {
+				Pipe::NorthWest => match direction {
 					South => (vec![current.east(), current.south()], vec![]),
 					East => (vec![], vec![current.east(), current.south()]),
-					x => return Err(format!("impossible J ({x:?})")),
+					x => return Err(Error::unsolvable(format!("impossible J ({x:?})"))),
 				}
-				'L' => match direction {
+				Pipe::NorthEast => match direction {
 					South => (vec![], vec![current.south(), current.west()]),
 					West => (vec![current.south(), current.west()], vec![]),
-					x => return Err(format!("impossible L ({x:?})")),
+					x => return Err(Error::unsolvable(format!("impossible L ({x:?})"))),
 				}
-				'7' => match direction {
+				Pipe::SouthWest => match direction {
 					North => (vec![], vec![current.north(), current.east()]),
 					East => (vec![current.north(), current.east()], vec![]),
-					x => return Err(format!("impossible 7 ({x:?})")),
+					x => return Err(Error::unsolvable(format!("impossible 7 ({x:?})"))),
 				}
-				'S' => (vec![], vec![]),
-				c => return Err(format!("impossible current pos ({c})")),
+				Pipe::Start | Pipe::Ground => return Err(Error::unsolvable("impossible current pos (ground)")),
 			};
-			let filtered_new_side1 = new_side1.iter().filter_map(|x| *x);
-			let filtered_new_side2 = new_side2.iter().filter_map(|x| *x);
-			side1.extend(filtered_new_side1);
-			side2.extend(filtered_new_side2);
-
-			direction = match curr_char {
-				// TODO infer S, until then... manually edit how S is handled
-				'S' => match direction {
-					East => South,
-					North => West,
-					x => return Err(format!("Can not go into S from {x:?} at {current:?}")),
-				}
-
-				'J' => match direction {
+			let off_map = |side: &[Option<Point2D>]| side.iter().any(|p| p.is_none_or(|p| !input.grid.contains(p)));
+			side1_off_map |= off_map(&new_side1);
+			side2_off_map |= off_map(&new_side2);
+			side1.extend(new_side1.into_iter().flatten().filter(|&p| input.grid.contains(p)));
+			side2.extend(new_side2.into_iter().flatten().filter(|&p| input.grid.contains(p)));
+
+			direction = match curr_pipe {
+				Pipe::NorthWest => match direction {
 					South => West,
 					East => North,
-					x => return Err(format!("Can not go into J from {x:?} at {current:?}")),
+					x => return Err(Error::unsolvable(format!("Can not go into J from {x:?} at {current:?}"))),
 				}
 
-				'F' => match direction {
+				Pipe::SouthEast => match direction {
 					North => East,
 					West => South,
-					x => return Err(format!("Can not go into F from {x:?} at {current:?}")),
+					x => return Err(Error::unsolvable(format!("Can not go into F from {x:?} at {current:?}"))),
 				}
 
-				'L' => match direction {
+				Pipe::NorthEast => match direction {
 					South => East,
 					West => North,
-					x => return Err(format!("Can not go into L from {x:?} at {current:?}")),
+					x => return Err(Error::unsolvable(format!("Can not go into L from {x:?} at {current:?}"))),
 				}
 
-				'7' =>
+				Pipe::SouthWest =>
 					match direction {
 						North => West,
 						East => South,
-						x => return Err(format!("Can not go into 7 from {x:?} at {current:?}")),
+						x => return Err(Error::unsolvable(format!("Can not go into 7 from {x:?} at {current:?}"))),
 					}
 
-				'-' | '|' => direction,
-				x => return Err(format!("Went {direction:?}, hit {x} and died")),
+				Pipe::Horizontal | Pipe::Vertical => direction,
+				Pipe::Start | Pipe::Ground => return Err(Error::unsolvable(format!("Went {direction:?}, hit the ground and died"))),
 			};
 
 			revisited.insert(current);
@@ -265,17 +370,30 @@ impl Solution for Day10 {
 
 		let s1: HashSet<Point2D> = side1.iter()
 			.filter(|&x| !visited.contains(x))
-			.map(|x| *x)
+			.copied()
 			.collect();
-		let s1e = extend(&info2, &visited, &s1);
+		let s1e = extend(input, &visited, &s1);
 
 		let s2: HashSet<Point2D> = side2.iter()
 			.filter(|&x| !visited.contains(x))
-			.map(|x| *x)
+			.copied()
 			.collect();
-		let s2e = extend(&info2, &visited, &s2);
-
-		Ok(format!("One of these (probably the smaller): {} / {}", s1e.len(), s2e.len()))
+		let s2e = extend(input, &visited, &s2);
+
+		// The side that touches the edge of the map is the outside
+		let s1_outside = side1_off_map || s1e.iter().any(|&p| input.grid.is_edge(p));
+		let s2_outside = side2_off_map || s2e.iter().any(|&p| input.grid.is_edge(p));
+		let inside = match (s1_outside, s2_outside) {
+			(true, false) => s2e,
+			(false, true) => s1e,
+			(true, true) => return Err(Error::unsolvable("Both sides of the loop touch the edge of the map")),
+			(false, false) => return Err(Error::unsolvable("Neither side of the loop touches the edge of the map")),
+		};
+		ctx.explain(format!("{} tiles inside the loop, the other side touches the edge of the map", inside.len()));
+		ctx.render("Tiles inside the loop", || input.grid.render()
+			.highlight(visited, Colour::Cyan)
+			.mark(inside.iter().copied(), 'I', Colour::Green));
+		Ok(inside.len().into())
 	}
 }
 
@@ -292,34 +410,20 @@ fn extend(
 	}
 
 	while let Some(point) = queue.pop_front() {
-		if let Some(north) = point.north() {
-			if !visited.contains(&north) && !full_set.contains(&north) && north.in_grid(info.max_x, info.max_y) {
-				visited.insert(north);
-				queue.push_back(north);
-			}
-		}
-
-		if let Some(south) = point.south() {
-			if !visited.contains(&south) && !full_set.contains(&south) && south.in_grid(info.max_x, info.max_y){
-				visited.insert(south);
-				queue.push_back(south);
-			}
-		}
-
-		if let Some(east) = point.east() {
-			if !visited.contains(&east) && !full_set.contains(&east) && east.in_grid(info.max_x, info.max_y){
-				visited.insert(east);
-				queue.push_back(east);
-			}
-		}
-
-		if let Some(west) = point.west() {
-			if !visited.contains(&west) && !full_set.contains(&west) && west.in_grid(info.max_x, info.max_y){
-				visited.insert(west);
-				queue.push_back(west);
+		for neighbour in info.grid.neighbours(point) {
+			if !visited.contains(&neighbour) && !full_set.contains(&neighbour) {
+				visited.insert(neighbour);
+				queue.push_back(neighbour);
 			}
 		}
 	}
 
 	visited
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day10: part_one, part_two);
+}
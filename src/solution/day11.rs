@@ -1,42 +1,51 @@
 use std::collections::VecDeque;
-use crate::solution::Solution;
-use crate::util::{Point2D, Point2DExt};
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Param, Solution, Status};
+use crate::util::{Cell, Grid, Point2D, Point2DExt};
 
+/// Day 11: Cosmic Expansion
 pub struct Day11 {
-	file: String,
+	source: InputSource,
 }
 
-type RawStarMap = Vec<Vec<char>>;
-type GalaxyList = Vec<Point2D>;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Space {
+	Empty,
+	Galaxy,
+}
 
-impl Day11 {
-	fn parse(&self) -> Result<RawStarMap, String> {
-		let map: RawStarMap = self.read_file_as_string()?.lines()
-			.map(|line| line.chars().collect())
-			.collect();
-		Ok(map)
+impl Cell for Space {
+	fn from_char(c: char) -> Option<Self> {
+		match c {
+			'.' => Some(Space::Empty),
+			'#' => Some(Space::Galaxy),
+			_ => None,
+		}
+	}
+
+	fn to_char(&self) -> char {
+		match self {
+			Space::Empty => '.',
+			Space::Galaxy => '#',
+		}
 	}
+}
 
+type RawStarMap = Grid<Space>;
+type GalaxyList = Vec<Point2D>;
+
+impl Day11 {
 	fn parse_galaxies(
 		&self,
+		star_map: &RawStarMap,
 		expansion: usize,
-	) -> Result<GalaxyList, String> {
-		let star_map = self.parse()?;
+	) -> Result<GalaxyList, Error> {
 		let mut galaxies: GalaxyList = Vec::new();
-		let y_len = star_map.len();
-		let x_len = star_map[0].len();
+		let x_len = star_map.width();
 
 		let mut empty_y = Vec::<usize>::new();
-		for y in 0..y_len {
-			let mut found = false;
-			for x in 0..x_len {
-				if star_map[y][x] == '#' {
-					found = true;
-					break;
-				}
-			}
-
-			if !found { empty_y.push(y); }
+		for (y, row) in star_map.rows().enumerate() {
+			if !row.contains(&Space::Galaxy) { empty_y.push(y); }
 		}
 
 		let mut found: bool = true;
@@ -45,12 +54,12 @@ impl Day11 {
 			if !found { xpansion += expansion; }
 			found = false;
 
-			for y in 0..star_map.len() {
+			for (y, row) in star_map.rows().enumerate() {
 				let ypansion = empty_y.iter()
 					.filter(|other| other < &&y)
 					.count() * expansion;
 
-				if star_map[y][x] == '#' {
+				if row[x] == Space::Galaxy {
 					found = true;
 					galaxies.push((x + xpansion, y + ypansion));
 				}
@@ -62,9 +71,10 @@ impl Day11 {
 
 	fn solve(
 		&self,
+		star_map: &RawStarMap,
 		expansion: usize,
-	) -> Result<String, String> {
-		let mut galaxies = VecDeque::from(self.parse_galaxies(expansion)?);
+	) -> Result<Answer, Error> {
+		let mut galaxies = VecDeque::from(self.parse_galaxies(star_map, expansion)?);
 
 		let mut distances = 0usize;
 		while let Some(galaxy) = galaxies.pop_front() {
@@ -73,22 +83,85 @@ impl Day11 {
 			});
 		};
 
-		Ok(distances.to_string())
+		Ok(distances.into())
 	}
 }
 
+const PARAMS: &[Param] = &[
+	Param { name: "expansion", description: "how many times larger empty space grows in part two", default: 1_000_000, min: 1 },
+];
+
+const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: EXAMPLE,
+		part_one: Some("374"),
+		part_two: None,
+		params: &[],
+	},
+	Example {
+		input: EXAMPLE,
+		part_one: None,
+		part_two: Some("1030"),
+		params: &[("expansion", 10)],
+	},
+	Example {
+		input: EXAMPLE,
+		part_one: None,
+		part_two: Some("8410"),
+		params: &[("expansion", 100)],
+	},
+];
+
 impl Solution for Day11 {
-	fn new(file: &str) -> Self { Day11 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
+	type Input = RawStarMap;
+
+	fn new(source: InputSource) -> Self { Day11 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 11, year: 2023, title: "Cosmic Expansion" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+	fn params() -> &'static [Param] { PARAMS }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		let map: RawStarMap = Grid::parse(input)?;
+		if map.height() == 0 { return Err(Error::invalid("The star map is empty")); }
+		Ok(map)
+	}
 
-	fn part_one(&self) -> Result<String, String> {
-		self.solve(1)
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		self.solve(input, 1)
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		// Don't understand why, but for distance of 1 you input 1.
-		// For higher distances, you subtract one from the distance.
-		// This goes for the example data with distance of 10 as well (10 too high, 9 correct).
-		self.solve(999_999)
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		// An empty row that becomes 10 rows only adds 9 to the distance
+		self.solve(input, ctx.param("expansion")? - 1)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day11: part_one, part_two);
+}
@@ -1,53 +1,109 @@
 use std::collections::VecDeque;
-use std::sync::atomic::{AtomicUsize, Ordering};
 
 use rayon::prelude::*;
 
-use crate::solution::Solution;
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Param, Solution, Status, Variant};
 
+/// Day 12: Hot Springs
 pub struct Day12 {
-	file: String,
+	source: InputSource,
+}
+
+/// Counts the arrangements of the springs that fit the groups.
+type CountFn = fn(&[char], &[usize], &Context) -> Result<usize, Error>;
+
+pub struct Record {
+	springs: Vec<char>,
+	groups: Vec<usize>,
 }
 
 impl Day12 {
 	fn solve(
 		&self,
+		records: &[Record],
 		copies: usize,
-	) -> Result<usize, String> {
-		let file = self.read_file_as_string()?;
-		let lines = file.lines().collect::<Vec<&str>>();
-
-		let done_count = AtomicUsize::new(0);
-		let count = lines.par_iter().filter_map(|line| {
-			let mut split = line.split(" ");
-			let pre_springs: Vec<char> = split.next()?.chars().collect();
+		count: CountFn,
+		ctx: &Context,
+	) -> Result<usize, Error> {
+		let progress = ctx.progress("Records", records.len());
+		let count = records.par_iter().map(|record| {
+			ctx.check_cancelled()?;
 			let springs: Vec<char> = (0..copies)
 				.flat_map(|i| {
-					let mut result = pre_springs.clone();
+					let mut result = record.springs.clone();
 					if i < copies - 1 { result.push('?'); }
 					result
 				}).collect();
 
-			let group_split = split.next()?;
-			let groups: Vec<usize> = group_split.split(",")
-				.map(|s| s.parse::<usize>().unwrap())
-				.collect::<Vec<usize>>();
-
-			let x = Some(count(&springs, &groups.repeat(copies)));
-			let done = done_count.fetch_add(1, Ordering::Relaxed);
-			println!("\x1B[2K\x1B[1GDone: {done}\x1B[1A");
-			x
-		}).sum::<usize>();
-		println!();
+			let x = count(&springs, &record.groups.repeat(copies), ctx)?;
+			progress.inc();
+			Ok(x)
+		}).try_reduce(|| 0, |a, b| Ok(a + b))?;
 
 		Ok(count)
 	}
+
+	fn part_one_enumerate(
+		&self,
+		input: &<Self as Solution>::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		Ok(self.solve(input, 1, count_enumerating, ctx)?.into())
+	}
+
+	fn part_two_enumerate(
+		&self,
+		input: &<Self as Solution>::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		// It works and isn't super memory hungry, but you need a pretty beefy CPU
+		// to run it in a... still pretty unreasonable time.
+		Ok(self.solve(input, ctx.param("copies")?, count_enumerating, ctx)?.into())
+	}
+}
+
+/// Counts the arrangements that fit the groups, working back from the end of the springs.
+/// `ways[i][j]` is the number of arrangements of `springs[i..]` that fit `groups[j..]`.
+fn count_arrangements(
+	springs: &[char],
+	groups: &[usize],
+	_ctx: &Context,
+) -> Result<usize, Error> {
+	let len = springs.len();
+	// One extra row for a group that ends right at the last spring and has no separator after it
+	let mut ways = vec![vec![0usize; groups.len() + 1]; len + 2];
+	ways[len][groups.len()] = 1;
+	ways[len + 1][groups.len()] = 1;
+
+	for i in (0..len).rev() {
+		for j in (0..=groups.len()).rev() {
+			let mut total = 0;
+			if springs[i] != '#' {
+				total += ways[i + 1][j];
+			}
+
+			if springs[i] != '.' && j < groups.len() {
+				let end = i + groups[j];
+				let fits = end <= len
+					&& springs[i..end].iter().all(|&c| c != '.')
+					&& springs.get(end) != Some(&'#');
+				if fits { total += ways[end + 1][j + 1]; }
+			}
+
+			ways[i][j] = total;
+		}
+	}
+
+	Ok(ways[0][0])
 }
 
-fn count(
-	springs: &Vec<char>,
-	groups: &Vec<usize>,
-) -> usize {
+/// Counts the arrangements that fit the groups by walking every one of them.
+fn count_enumerating(
+	springs: &[char],
+	groups: &[usize],
+	ctx: &Context,
+) -> Result<usize, Error> {
 	let mut ok_count: usize = 0;
 	let mut progresses: VecDeque<(usize, RecordProgress)> = VecDeque::from(vec![
 		(0, RecordProgress {
@@ -58,6 +114,7 @@ fn count(
 	]);
 
 	while let Some((mut pos, mut rp)) = progresses.pop_back() {
+		ctx.check_cancelled()?;
 		loop {
 			if pos == springs.len() {
 				if rp.group_index == groups.len() && Some(&rp.broke_in_group) == groups.last() {
@@ -89,7 +146,7 @@ fn count(
 		}
 	};
 
-	ok_count
+	Ok(ok_count)
 }
 
 #[derive(Debug, Clone)]
@@ -102,7 +159,7 @@ struct RecordProgress {
 impl RecordProgress {
 	fn move_dot(
 		&mut self,
-		groups: &Vec<usize>,
+		groups: &[usize],
 	) -> bool {
 		if self.broke_in_group > 0 {
 			self.new_group = true;
@@ -115,7 +172,7 @@ impl RecordProgress {
 
 	fn move_hash(
 		&mut self,
-		groups: &Vec<usize>,
+		groups: &[usize],
 	) -> bool {
 		self.broke_in_group += 1;
 
@@ -133,17 +190,83 @@ impl RecordProgress {
 	}
 }
 
+const PARAMS: &[Param] = &[
+	Param { name: "copies", description: "how many times the records are unfolded in part two", default: 5, min: 1 },
+];
+
+const VARIANTS: &[Variant<Day12>] = &[
+	Variant { part: 1, name: "enumerate", description: "walks every arrangement", solve: Day12::part_one_enumerate },
+	Variant { part: 2, name: "enumerate", description: "walks every arrangement", solve: Day12::part_two_enumerate },
+];
+
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+",
+		part_one: Some("21"),
+		part_two: Some("525152"),
+		params: &[],
+	},
+];
+
 impl Solution for Day12 {
-	fn new(file: &str) -> Self { Day12 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
+	type Input = Vec<Record>;
+
+	fn new(source: InputSource) -> Self { Day12 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 12, year: 2023, title: "Hot Springs" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+	fn params() -> &'static [Param] { PARAMS }
+	fn variants() -> &'static [Variant<Self>] { VARIANTS }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		input.lines().enumerate().map(|(i, line)| {
+			let mut split = line.split(' ');
+			let springs: Vec<char> = split.next()
+				.ok_or(Error::parse(format!("Missing springs in {line}")).at_line(i + 1))?
+				.chars()
+				.collect();
+			if let Some(x) = springs.iter().position(|c| !matches!(c, '.' | '#' | '?')) {
+				return Err(Error::parse(format!("Invalid spring: {:?}", springs[x])).at_line(i + 1).at_column(x + 1));
+			}
+
+			let groups = split.next()
+				.ok_or(Error::parse(format!("Missing groups in {line}")).at_line(i + 1))?
+				.split(',')
+				.map(|s| s.parse::<usize>().map_err(|_| Error::parse(format!("Invalid group size: {s}")).at_line(i + 1)))
+				.collect::<Result<Vec<usize>, Error>>()?;
 
-	fn part_one(&self) -> Result<String, String> {
-		Ok(self.solve(1)?.to_string())
+			Ok(Record { springs, groups })
+		}).collect()
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		// It works and isn't super memory hungry, but you need a pretty beefy CPU
-		// to run it in a... still pretty unreasonable time.
-		Ok(self.solve(5)?.to_string())
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		Ok(self.solve(input, 1, count_arrangements, ctx)?.into())
 	}
+
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		Ok(self.solve(input, ctx.param("copies")?, count_arrangements, ctx)?.into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day12: part_one, part_two);
 }
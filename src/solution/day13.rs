@@ -1,45 +1,37 @@
 use std::cmp::min;
 
-use crate::solution::Solution;
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Solution, Status};
+use crate::util::Grid;
 
+/// Day 13: Point of Incidence
 pub struct Day13 {
-	file: String,
+	source: InputSource,
 }
 
 #[derive(Debug)]
-struct Pattern {
+pub struct Pattern {
 	columns: Vec<String>,
 	rows: Vec<String>,
 }
 
 impl Day13 {
-	fn parse(&self) -> Result<Vec<Pattern>, String> {
-		self.read_file_as_string()?
-			.split("\n\n")
-			.map(|it| self.parse_pattern(it))
-			.collect::<Result<Vec<Pattern>, String>>()
-	}
-
 	fn parse_pattern(
-		&self,
 		pattern: &str,
-	) -> Result<Pattern, String> {
-		let rows = pattern.lines().map(|it| it.to_string()).collect::<Vec<String>>();
-		let mut columns = vec![];
-		for x in 0..rows[0].len() {
-			let mut column = String::with_capacity(rows.len());
-			for y in 0..rows.len() {
-				column.push(rows[y].chars().nth(x).unwrap());
-			}
-			columns.push(column);
-		}
-
+		first_line: usize,
+	) -> Result<Pattern, Error> {
+		let grid = Grid::parse_with(pattern, |c| matches!(c, '#' | '.').then_some(c))
+			.map_err(|e| e.offset_line(first_line - 1))?;
+		if grid.width() == 0 { return Err(Error::parse("Empty pattern").at_line(first_line)); }
+
+		let rows = grid.rows().map(|row| row.iter().collect()).collect();
+		let columns = grid.columns().map(|column| column.collect()).collect();
 		Ok(Pattern { columns, rows })
 	}
 }
 
 fn find_reflection_line(
-	lines: &Vec<String>,
+	lines: &[String],
 	expected_diffs: usize,
 ) -> Option<usize> {
 	for i1 in 0..(lines.len() - 1) {
@@ -65,8 +57,8 @@ fn find_reflection_line(
 }
 
 fn count_up_to_2_diffs(
-	line1: &String,
-	line2: &String,
+	line1: &str,
+	line2: &str,
 ) -> usize {
 	let mut diffs = 0;
 	for i in 0..line1.len() {
@@ -79,16 +71,62 @@ fn count_up_to_2_diffs(
 	diffs
 }
 
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.##..##.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+",
+		part_one: Some("405"),
+		part_two: Some("400"),
+		params: &[],
+	},
+];
+
 impl Solution for Day13 {
-	fn new(file: &str) -> Self { Day13 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
+	type Input = Vec<Pattern>;
 
-	fn part_one(&self) -> Result<String, String> {
-		let patterns = self.parse()?;
+	fn new(source: InputSource) -> Self { Day13 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 13, year: 2023, title: "Point of Incidence" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		let mut first_line = 1;
+		input
+			.split("\n\n")
+			.map(|pattern| {
+				let parsed = Day13::parse_pattern(pattern, first_line);
+				first_line += pattern.lines().count() + 1;
+				parsed
+			})
+			.collect()
+	}
+
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		let patterns = input;
 
 		let mut left: usize = 0;
 		let mut above: usize = 0;
-		for pattern in &patterns {
+		for pattern in patterns {
 			if let Some(row) = find_reflection_line(&pattern.rows, 0) {
 				above += row + 1;
 				continue;
@@ -99,15 +137,20 @@ impl Solution for Day13 {
 			};
 		}
 
-		Ok(format!("{above} * 100 + {left} = {}", above * 100 + left))
+		ctx.explain(format!("{above} rows above and {left} columns left of the mirrors: {above} * 100 + {left}"));
+		Ok((above * 100 + left).into())
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		let patterns = self.parse()?;
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		let patterns = input;
 
 		let mut left: usize = 0;
 		let mut above: usize = 0;
-		for pattern in &patterns {
+		for pattern in patterns {
 			if let Some(row) = find_reflection_line(&pattern.rows, 1) {
 				above += row + 1;
 				continue;
@@ -118,6 +161,14 @@ impl Solution for Day13 {
 			};
 		}
 
-		Ok(format!("{above} * 100 + {left} = {}", above * 100 + left))
+		ctx.explain(format!("{above} rows above and {left} columns left of the mirrors: {above} * 100 + {left}"));
+		Ok((above * 100 + left).into())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day13: part_one, part_two);
+}
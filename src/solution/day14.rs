@@ -1,38 +1,100 @@
-use crate::solution::Solution;
-use crate::util::{Matrix2D, Matrix2DExt};
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Param, Solution, Status};
+use crate::util::{Cell, Grid};
 
+/// Day 14: Parabolic Reflector Dish
 pub struct Day14 {
-	file: String,
+	source: InputSource,
 }
 
-type Matrix = Matrix2D<char>;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rock {
+	Round,
+	Cube,
+	Empty,
+}
+
+impl Cell for Rock {
+	fn from_char(c: char) -> Option<Self> {
+		match c {
+			'O' => Some(Rock::Round),
+			'#' => Some(Rock::Cube),
+			'.' => Some(Rock::Empty),
+			_ => None,
+		}
+	}
 
-impl Day14 {
-	fn parse(&self) -> Result<Matrix, String> {
-		let file = self.read_file_as_string()?;
-		Ok(file.lines()
-			.map(|s| s.chars().collect::<Vec<char>>())
-			.collect::<Matrix>())
+	fn to_char(&self) -> char {
+		match self {
+			Rock::Round => 'O',
+			Rock::Cube => '#',
+			Rock::Empty => '.',
+		}
 	}
 }
 
+type Matrix = Grid<Rock>;
+
+const PARAMS: &[Param] = &[
+	Param { name: "cycles", description: "spin cycles in part two", default: 1_000_000_000, min: 1 },
+];
+
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+",
+		part_one: Some("136"),
+		part_two: Some("64"),
+		params: &[],
+	},
+];
+
 impl Solution for Day14 {
-	fn new(file: &str) -> Self { Day14 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
+	type Input = Matrix;
 
-	fn part_one(&self) -> Result<String, String> {
-		let mut matrix = self.parse()?;
+	fn new(source: InputSource) -> Self { Day14 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 14, year: 2023, title: "Parabolic Reflector Dish" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+	fn params() -> &'static [Param] { PARAMS }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		Grid::parse(input)
+	}
+
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		let mut matrix = input.clone();
 		for y in matrix.y_range() {
 			for x in matrix.x_range() {
 				roll_north(&mut matrix, x, y);
 			}
 		}
 
-		Ok(count_load(&matrix).to_string())
+		Ok(count_load(&matrix).into())
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		let mut matrix = self.parse()?;
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		let cycles = ctx.param("cycles")?;
+		let mut matrix = input.clone();
 		let mut history = vec![];
 
 		loop {
@@ -62,14 +124,15 @@ impl Solution for Day14 {
 
 			if history.contains(&matrix) { break; }
 			history.push(matrix.clone());
+			if history.len() == cycles { return Ok(count_load(&matrix).into()); }
 		}
 
 		let offset = history.iter().position(|m| m == &matrix).unwrap();
 		let trim_len = history.len() - offset;
-		let idx = (1_000_000_000 - offset - 1) % trim_len;
+		let idx = (cycles - offset - 1) % trim_len;
 		let final_m = history.iter().skip(offset).nth(idx).unwrap();
 
-		Ok(count_load(&final_m).to_string())
+		Ok(count_load(final_m).into())
 	}
 }
 
@@ -79,12 +142,12 @@ fn roll_north(
 	y: usize,
 ) {
 	if y == 0 { return; }
-	let here = matrix.get_xy(x, y);
-	let there = matrix.get_xy(x, y - 1);
+	let here = matrix.get_point((x, y));
+	let there = matrix.get_point((x, y - 1));
 
-	if here == Some(&'O') && there == Some(&'.') {
-		matrix.set_xy(x, y, '.');
-		matrix.set_xy(x, y - 1, 'O');
+	if here == Some(&Rock::Round) && there == Some(&Rock::Empty) {
+		matrix.set_point((x, y), Rock::Empty);
+		matrix.set_point((x, y - 1), Rock::Round);
 		roll_north(matrix, x, y - 1);
 	}
 }
@@ -94,13 +157,13 @@ fn roll_south(
 	x: usize,
 	y: usize,
 ) {
-	if y == matrix.y_len() - 1 { return; }
-	let here = matrix.get_xy(x, y);
-	let there = matrix.get_xy(x, y + 1);
+	if y == matrix.height() - 1 { return; }
+	let here = matrix.get_point((x, y));
+	let there = matrix.get_point((x, y + 1));
 
-	if here == Some(&'O') && there == Some(&'.') {
-		matrix.set_xy(x, y, '.');
-		matrix.set_xy(x, y + 1, 'O');
+	if here == Some(&Rock::Round) && there == Some(&Rock::Empty) {
+		matrix.set_point((x, y), Rock::Empty);
+		matrix.set_point((x, y + 1), Rock::Round);
 		roll_south(matrix, x, y + 1);
 	}
 }
@@ -111,12 +174,12 @@ fn roll_west(
 	y: usize,
 ) {
 	if x == 0 { return; }
-	let here = matrix.get_xy(x, y);
-	let there = matrix.get_xy(x - 1, y);
+	let here = matrix.get_point((x, y));
+	let there = matrix.get_point((x - 1, y));
 
-	if here == Some(&'O') && there == Some(&'.') {
-		matrix.set_xy(x, y, '.');
-		matrix.set_xy(x - 1, y, 'O');
+	if here == Some(&Rock::Round) && there == Some(&Rock::Empty) {
+		matrix.set_point((x, y), Rock::Empty);
+		matrix.set_point((x - 1, y), Rock::Round);
 		roll_west(matrix, x - 1, y);
 	}
 }
@@ -126,13 +189,13 @@ fn roll_east(
 	x: usize,
 	y: usize,
 ) {
-	if x == matrix.x_len() - 1 { return; }
-	let here = matrix.get_xy(x, y);
-	let there = matrix.get_xy(x + 1, y);
+	if x == matrix.width() - 1 { return; }
+	let here = matrix.get_point((x, y));
+	let there = matrix.get_point((x + 1, y));
 
-	if here == Some(&'O') && there == Some(&'.') {
-		matrix.set_xy(x, y, '.');
-		matrix.set_xy(x + 1, y, 'O');
+	if here == Some(&Rock::Round) && there == Some(&Rock::Empty) {
+		matrix.set_point((x, y), Rock::Empty);
+		matrix.set_point((x + 1, y), Rock::Round);
 		roll_east(matrix, x + 1, y);
 	}
 }
@@ -140,10 +203,17 @@ fn roll_east(
 fn count_load(
 	matrix: &Matrix,
 ) -> usize {
-	matrix.iter()
-		.map(|x| x.iter().filter(|&&c| c == 'O').count())
+	matrix.rows()
+		.map(|x| x.iter().filter(|&&rock| rock == Rock::Round).count())
 		.rev()
 		.enumerate()
 		.map(|(idx, value)|  value * (idx+1))
 		.sum()
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day14: part_one, part_two);
+}
@@ -1,10 +1,12 @@
-use crate::solution::Solution;
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Solution, Status};
 
+/// Day 15: Lens Library
 pub struct Day15 {
-	file: String,
+	source: InputSource,
 }
 
-struct Step {
+pub struct Step {
 	raw: String,
 	label: String,
 	operation: Operation,
@@ -16,36 +18,22 @@ enum Operation {
 	Set(usize),
 }
 
-impl Day15 {
-	fn parse(&self) -> Result<Vec<Step>, String> {
-		Ok(self.read_file_as_string()?
-			.split(',')
-			.map(|cs| parse_step(cs))
-			.collect::<Result<Vec<Step>, String>>()?)
-	}
-}
-
-fn parse_step(step: &str) -> Result<Step, String> {
-	let mut i = 0usize;
-	let chars = step.chars().collect::<Vec<char>>();
-	let mut label = String::new();
+fn parse_step(step: &str) -> Result<Step, Error> {
 	let raw = step.trim().to_string();
-
-	while chars[i] != '=' && chars[i] != '-' {
-		if !chars[i].is_whitespace() { label.push(chars[i]); }
-		i += 1;
-	}
-
-	let operation = match chars[i] {
-		'=' => Operation::Set(chars[i+1].to_string().parse().unwrap()),
-		'-' => Operation::Remove,
-		c => return Err(format!("Invalid operation: {c}")),
+	let i = step.find(['=', '-'])
+		.ok_or(Error::parse(format!("Missing operation in step {raw:?}")))?;
+	let label = step[..i].chars().filter(|c| !c.is_whitespace()).collect::<String>();
+
+	let operation = match &step[i..] {
+		"-" => Operation::Remove,
+		set => Operation::Set(set[1..].trim().parse()
+			.map_err(|_| Error::parse(format!("Invalid focal length in step {raw:?}")).at_column(i + 2))?),
 	};
 
 	Ok(Step { raw, label, operation })
 }
 
-fn hash(s: &String) -> usize {
+fn hash(s: &str) -> usize {
 	let mut out = 0;
 	for char in s.chars() {
 		out += char as usize;
@@ -56,41 +44,70 @@ fn hash(s: &String) -> usize {
 	out
 }
 
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7
+",
+		part_one: Some("1320"),
+		part_two: Some("145"),
+		params: &[],
+	},
+];
+
 impl Solution for Day15 {
-	fn new(file: &str) -> Self { Day15 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
+	type Input = Vec<Step>;
+
+	fn new(source: InputSource) -> Self { Day15 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 15, year: 2023, title: "Lens Library" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		let mut column = 1;
+		input
+			.trim_end()
+			.split(',')
+			.map(|step| {
+				let parsed = parse_step(step).map_err(|e| e.at_column(1).offset_column(column - 1).at_line(1));
+				column += step.len() + 1;
+				parsed
+			})
+			.collect()
+	}
 
-	fn part_one(&self) -> Result<String, String> {
-		Ok(self.parse()?
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		Ok(input
 			.iter()
 			.map(|s| hash(&s.raw))
 			.sum::<usize>()
-			.to_string())
+			.into())
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		let steps = self.parse()?;
-
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
 		// &String is the label, usize is the lens
 		let mut boxes: Vec<Vec<(String, usize)>> = (0..256).map(|_| Vec::new()).collect();
 
-		for step in steps {
+		for step in input {
 			let step_box = boxes.get_mut(hash(&step.label))
-				.ok_or(format!("Invalid hash for {} ({})", &step.label, hash(&step.label)))?;
+				.ok_or(Error::invalid(format!("Invalid hash for {} ({})", &step.label, hash(&step.label))))?;
 
 			match step.operation {
 				Operation::Remove => step_box.retain(|(label, _)| label != &step.label),
 				Operation::Set(lens) => {
-					let mut replaced = false;
-					for i in 0..step_box.len() {
-						if step_box[i].0 == step.label {
-							step_box[i].1 = lens;
-							replaced = true;
-							break;
-						}
+					match step_box.iter_mut().find(|(label, _)| label == &step.label) {
+						Some(slot) => slot.1 = lens,
+						None => step_box.push((step.label.clone(), lens)),
 					}
-
-					if !replaced { step_box.push((step.label, lens)); }
 				},
 			};
 		}
@@ -102,6 +119,13 @@ impl Solution for Day15 {
 			}
 		}
 
-		Ok(out.to_string())
+		Ok(out.into())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day15: part_one, part_two);
+}
@@ -1,12 +1,14 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashSet, VecDeque};
 
 use Direction::{East, North, South, West};
 
-use crate::solution::Solution;
-use crate::util::{Direction, Point2D, Point2DExt};
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Solution, Status};
+use crate::util::{Cell, Colour, Direction, Grid, Point2D, Point2DExt};
 
+/// Day 16: The Floor Will Be Lava
 pub struct Day16 {
-	file: String,
+	source: InputSource,
 }
 
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
@@ -16,82 +18,137 @@ struct Ray {
 }
 
 impl Ray {
-	fn new(
+	fn turn(
 		&self,
 		dir: Option<Direction>,
-		max_x: usize,
-		max_y: usize,
+		grid: &Grid<Tile>,
 	) -> Option<Self> {
 		let dir = dir?;
-		let pos = self.pos.move_dir(&dir).filter(|x| x.in_grid(max_x, max_y))?;
+		let pos = self.pos.move_dir(&dir).filter(|&pos| grid.contains(pos))?;
 		Some(Ray { pos, dir })
 	}
 }
 
-#[derive(Debug)]
-struct Grid {
-	map: HashMap<Point2D, char>,
-	max_x: usize,
-	max_y: usize,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+	Empty,
+	/// `/`
+	MirrorUp,
+	/// `\`
+	MirrorDown,
+	/// `-`
+	SplitterHorizontal,
+	/// `|`
+	SplitterVertical,
 }
 
-impl Day16 {
-	fn parse(&self) -> Result<Grid, String> {
-		let file = self.read_file_as_string()?;
-		let mut map = HashMap::new();
-		let mut max_x = 0;
-		let mut max_y = 0;
-		for (y, line) in file.lines().enumerate() {
-			max_y = y;
-			for (x, c) in line.chars().enumerate() {
-				max_x = x;
-				if c == '.' { continue; }
-				map.insert((x, y), c);
-			}
+impl Cell for Tile {
+	fn from_char(c: char) -> Option<Self> {
+		match c {
+			'.' => Some(Tile::Empty),
+			'/' => Some(Tile::MirrorUp),
+			'\\' => Some(Tile::MirrorDown),
+			'-' => Some(Tile::SplitterHorizontal),
+			'|' => Some(Tile::SplitterVertical),
+			_ => None,
 		}
+	}
 
-		Ok(Grid { map, max_x, max_y })
+	fn to_char(&self) -> char {
+		match self {
+			Tile::Empty => '.',
+			Tile::MirrorUp => '/',
+			Tile::MirrorDown => '\\',
+			Tile::SplitterHorizontal => '-',
+			Tile::SplitterVertical => '|',
+		}
 	}
 }
 
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+.|...\\....
+|.-.\\.....
+.....|-...
+........|.
+..........
+.........\\
+..../.\\\\..
+.-.-/..|..
+.|....-|.\\
+..//.|....
+",
+		part_one: Some("46"),
+		part_two: Some("51"),
+		params: &[],
+	},
+];
+
 impl Solution for Day16 {
-	fn new(file: &str) -> Self { Day16 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
+	type Input = Grid<Tile>;
 
-	fn part_one(&self) -> Result<String, String> {
-		let grid = self.parse()?;
-		let start = Ray { pos: (0, 0), dir: East };
-		Ok(solve(&grid, start)?.to_string())
+	fn new(source: InputSource) -> Self { Day16 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 16, year: 2023, title: "The Floor Will Be Lava" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		Grid::parse(input)
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		let grid = self.parse()?;
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		let energized = energized(input, Ray { pos: (0, 0), dir: East });
+		ctx.render("Energized tiles", || input.render().mark(energized.iter().copied(), '#', Colour::Yellow));
+		Ok(energized.len().into())
+	}
+
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		let grid = input;
 
 		let mut max = 0;
-		for x in 0..=grid.max_x {
-			for y in 0..=grid.max_y {
-				if x == 0 { max = max.max(solve(&grid, Ray { pos: (x, y), dir: East })?); }
-				if x == grid.max_x { max = max.max(solve(&grid, Ray { pos: (x, y), dir: West })?); }
-				if y == 0 { max = max.max(solve(&grid, Ray { pos: (x, y), dir: South })?); }
-				if y == grid.max_y { max = max.max(solve(&grid, Ray { pos: (x, y), dir: North })?); }
+		for x in grid.x_range() {
+			for y in grid.y_range() {
+				if x == 0 { max = max.max(solve(grid, Ray { pos: (x, y), dir: East })?); }
+				if x == grid.width() - 1 { max = max.max(solve(grid, Ray { pos: (x, y), dir: West })?); }
+				if y == 0 { max = max.max(solve(grid, Ray { pos: (x, y), dir: South })?); }
+				if y == grid.height() - 1 { max = max.max(solve(grid, Ray { pos: (x, y), dir: North })?); }
 			}
 		}
 
-		Ok(max.to_string())
+		Ok(max.into())
 	}
 }
 
 fn solve(
-	grid: &Grid,
+	grid: &Grid<Tile>,
+	start: Ray,
+) -> Result<usize, Error> {
+	Ok(energized(grid, start).len())
+}
+
+/// Every tile a beam passes through when it enters the grid as `start`.
+fn energized(
+	grid: &Grid<Tile>,
 	start: Ray,
-) -> Result<usize, String> {
+) -> HashSet<Point2D> {
+	if !grid.contains(start.pos) { return HashSet::new(); }
 	let mut rays = HashSet::new();
 	rays.insert(start.clone());
 
 	let mut queue = VecDeque::from(vec![start]);
 
 	while let Some(ray) = queue.pop_front() {
-		let (r1, r2) = move_ray(ray, &grid.map, grid.max_x, grid.max_y);
+		let (r1, r2) = move_ray(ray, grid);
 
 		if let Some(r1) = r1 {
 			if !rays.contains(&r1) {
@@ -108,39 +165,43 @@ fn solve(
 		}
 	}
 
-	Ok(rays.iter().map(|p| p.pos).collect::<HashSet<Point2D>>().len())
+	rays.iter().map(|p| p.pos).collect()
 }
 
 fn move_ray(
 	ray: Ray,
-	map: &HashMap<Point2D, char>,
-	max_x: usize,
-	max_y: usize,
+	grid: &Grid<Tile>,
 ) -> (Option<Ray>, Option<Ray>) {
-	let (dir1, dir2) = match map.get(&ray.pos) {
-		None => (Some(ray.dir), None),
-		Some('/') => match ray.dir {
+	let (dir1, dir2) = match grid[ray.pos] {
+		Tile::Empty => (Some(ray.dir), None),
+		Tile::MirrorUp => match ray.dir {
 			North => (Some(East), None),
 			South => (Some(West), None),
 			East => (Some(North), None),
 			West => (Some(South), None),
 		},
-		Some('\\') => match ray.dir {
+		Tile::MirrorDown => match ray.dir {
 			North => (Some(West), None),
 			South => (Some(East), None),
 			East => (Some(South), None),
 			West => (Some(North), None),
 		},
-		Some('-') => match ray.dir {
+		Tile::SplitterHorizontal => match ray.dir {
 			North | South => (Some(East), Some(West)),
 			dir => (Some(dir), None),
 		},
-		Some('|') => match ray.dir {
+		Tile::SplitterVertical => match ray.dir {
 			East | West => (Some(North), Some(South)),
 			dir => (Some(dir), None),
 		}
-		Some(char) => panic!("Unknown char: {}", char),
 	};
 
-	(ray.new(dir1, max_x, max_y), ray.new(dir2, max_x, max_y))
+	(ray.turn(dir1, grid), ray.turn(dir2, grid))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day16: part_one, part_two);
 }
@@ -3,14 +3,17 @@ use std::collections::{BinaryHeap, HashMap};
 
 use Direction::{East, North, South, West};
 
-use crate::solution::Solution;
-use crate::util::{Direction, Matrix2D, Matrix2DExt, Point2D, Point2DExt};
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Param, Solution, Status, Variant};
+use crate::util::{Colour, Direction, Grid, Point2D, Point2DExt};
 
+/// Day 17: Clumsy Crucible
 pub struct Day17 {
-	file: String,
+	source: InputSource,
 }
 
-type Grid = Matrix2D<usize>;
+/// Where a walker is, where it is going and how many steps it has taken in that direction.
+type State = (Point2D, Direction, usize);
 
 #[derive(Debug, Eq)]
 struct Walker {
@@ -19,6 +22,8 @@ struct Walker {
 	steps: usize,
 	cost: usize,
 	suboptimal_steps: usize,
+	/// The state the walker was in one step ago.
+	prev: Option<State>,
 }
 
 impl PartialEq<Self> for Walker {
@@ -34,63 +39,103 @@ impl Ord for Walker {
 }
 
 impl Walker {
+	fn state(&self) -> State { (self.pos, self.dir, self.steps) }
+
 	fn walk(
 		&self,
 		dir: Direction,
-		grid: &Grid,
+		grid: &Grid<usize>,
 		min_steps: usize,
 		max_steps: usize,
 	) -> Option<Walker> {
-		if dir == self.dir && self.steps == max_steps {
-			return None;
-		} else if dir != self.dir && self.steps < min_steps {
+		if (dir == self.dir && self.steps == max_steps) || (dir != self.dir && self.steps < min_steps) {
 			return None;
 		}
 
 		match self.pos.move_dir(&dir) {
-			Some(pos) if pos.in_matrix(&grid) => {
-				let cost = self.cost + *grid.get_point(pos).unwrap();
+			Some(pos) if grid.contains(pos) => {
+				let cost = self.cost + grid[pos];
 				let steps = if dir == self.dir { self.steps + 1 } else { 1 };
-				Some(Walker { pos, dir, steps, cost, suboptimal_steps: self.suboptimal_steps })
+				Some(Walker { pos, dir, steps, cost, suboptimal_steps: self.suboptimal_steps, prev: Some(self.state()) })
 			}
 			_ => None,
 		}
 	}
 }
 
-impl Day17 {
-	fn parse(&self) -> Result<Grid, String> {
-		Ok(self.read_file_as_string()?.lines().into_iter()
-			.map(|line| {
-				line.chars()
-					.filter_map(|c| c.to_digit(10))
-					.map(|d| d as usize)
-					.collect::<Vec<usize>>()
-			}).collect())
+/// The cheapest way to the bottom right and the points along it, keeping track of the best cost
+/// for every position, direction and number of steps taken in that direction.
+fn cheapest_path(
+	grid: &Grid<usize>,
+	min_steps: usize,
+	max_steps: usize,
+) -> Option<(usize, Vec<Point2D>)> {
+	let goal: Point2D = (grid.width() - 1, grid.height() - 1);
+	let all_dir = [North, South, East, West];
+
+	let w1 = Walker { pos: (0, 1), dir: South, steps: 1, cost: grid[(0, 1)], suboptimal_steps: 0, prev: None };
+	let w2 = Walker { pos: (1, 0), dir: East, steps: 1, cost: grid[(1, 0)], suboptimal_steps: 0, prev: None };
+	let mut walkers = BinaryHeap::from(vec![w1, w2]);
+	// Every state that has been reached, with the state it was first reached from
+	let mut visited = HashMap::new();
+
+	while let Some(walker) = walkers.pop() {
+		if walker.pos == goal && walker.steps >= min_steps {
+			let mut path = vec![walker.pos];
+			let mut prev = walker.prev;
+			while let Some(state) = prev {
+				path.push(state.0);
+				prev = visited[&state];
+			}
+			path.push((0, 0));
+			path.reverse();
+			return Some((walker.cost, path));
+		}
+		if visited.contains_key(&walker.state()) { continue; }
+		visited.insert(walker.state(), walker.prev);
+
+		all_dir.iter()
+			.filter(|dir| dir != &&walker.dir.opposite())
+			.filter_map(|&dir| walker.walk(dir, grid, min_steps, max_steps))
+			.for_each(|new_walker| walkers.push(new_walker));
 	}
+
+	None
 }
 
-fn cheapest_path(
-	grid: &Grid,
+fn render_path(
+	grid: &Grid<usize>,
+	path: &[Point2D],
+	ctx: &Context,
+) {
+	ctx.render("Cheapest path", || grid
+		.render_with(|&loss| char::from_digit(loss as u32, 10).unwrap_or('?'))
+		.path(path, Colour::Red));
+}
+
+/// Like [cheapest_path], but only keeps track of the best cost per position and gives walkers
+/// a few steps of slack when they are behind. Quick, but misses paths that need more slack.
+fn cheapest_path_heuristic(
+	grid: &Grid<usize>,
 	min_steps: usize,
 	max_steps: usize,
-) -> usize {
-	let goal: Point2D = (grid.x_len() - 1, grid.y_len() - 1);
-	let all_dir = vec![North, South, East, West];
+) -> Option<usize> {
+	let goal: Point2D = (grid.width() - 1, grid.height() - 1);
+	let all_dir = [North, South, East, West];
 
 	let mut cost_map = HashMap::new();
 	cost_map.insert((0,0), 0);
 
 	// Starting positions
-	let w1 = Walker { pos: (0, 1), dir: South, steps: 1, cost: grid[1][0], suboptimal_steps: 0 };
-	let w2 = Walker { pos: (1, 0), dir: East, steps: 1, cost: grid[0][1], suboptimal_steps: 0 };
+	let w1 = Walker { pos: (0, 1), dir: South, steps: 1, cost: grid[(0, 1)], suboptimal_steps: 0, prev: None };
+	let w2 = Walker { pos: (1, 0), dir: East, steps: 1, cost: grid[(1, 0)], suboptimal_steps: 0, prev: None };
 	let mut walkers = BinaryHeap::from(vec![w1, w2]);
 	let mut best_goal = usize::MAX;
 
 	while let Some(walker) = walkers.pop() {
 		let new_walkers = all_dir.iter()
 			.filter(|dir| dir != &&walker.dir.opposite())
-			.filter_map(|&dir| walker.walk(dir, &grid, min_steps, max_steps));
+			.filter_map(|&dir| walker.walk(dir, grid, min_steps, max_steps));
 
 		for mut new_walker in new_walkers {
 			if new_walker.cost >= best_goal { continue; }
@@ -122,22 +167,131 @@ fn cheapest_path(
 		}
 	}
 
-	cost_map[&goal]
+	cost_map.get(&goal).copied()
+}
+
+impl Day17 {
+	fn part_one_heuristic(
+		&self,
+		input: &Grid<usize>,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		let cheapest = cheapest_path_heuristic(input, 0, 3).ok_or(Error::unsolvable("There is no path to the factory"))?;
+		Ok(cheapest.into())
+	}
+
+	fn part_two_heuristic(
+		&self,
+		input: &Grid<usize>,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		let (min_moves, max_moves) = ultra_moves(ctx)?;
+		let cheapest = cheapest_path_heuristic(input, min_moves, max_moves).ok_or(Error::unsolvable("There is no path to the factory"))?;
+		Ok(cheapest.into())
+	}
 }
 
+/// How far an ultra crucible moves at least and at most before turning.
+fn ultra_moves(ctx: &Context) -> Result<(usize, usize), Error> {
+	let min_moves = ctx.param("min_moves")?;
+	let max_moves = ctx.param("max_moves")?;
+	if min_moves > max_moves {
+		return Err(Error::invalid(format!("min_moves ({min_moves}) can not be larger than max_moves ({max_moves})")));
+	}
+
+	Ok((min_moves, max_moves))
+}
+
+const VARIANTS: &[Variant<Day17>] = &[
+	Variant { part: 1, name: "heuristic", description: "best cost per position, with some slack", solve: Day17::part_one_heuristic },
+	Variant { part: 2, name: "heuristic", description: "best cost per position, with some slack", solve: Day17::part_two_heuristic },
+];
+
+const PARAMS: &[Param] = &[
+	Param { name: "min_moves", description: "blocks an ultra crucible moves before it can turn", default: 4, min: 1 },
+	Param { name: "max_moves", description: "blocks an ultra crucible can move in a straight line", default: 10, min: 1 },
+];
+
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+",
+		part_one: Some("102"),
+		part_two: Some("94"),
+		params: &[],
+	},
+	Example {
+		input: "\
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991
+",
+		part_one: None,
+		part_two: Some("71"),
+		params: &[],
+	},
+];
+
 impl Solution for Day17 {
-	fn new(file: &str) -> Self { Day17 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
+	type Input = Grid<usize>;
+
+	fn new(source: InputSource) -> Self { Day17 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 17, year: 2023, title: "Clumsy Crucible" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+	fn params() -> &'static [Param] { PARAMS }
+	fn variants() -> &'static [Variant<Self>] { VARIANTS }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		let grid = Grid::parse_with(input, |c| c.to_digit(10).map(|d| d as usize))?;
+		// The crucible starts with a step right or down, so both have to fit
+		if grid.width() < 2 || grid.height() < 2 {
+			return Err(Error::invalid(format!("The map is {}x{}, but has to be at least 2x2", grid.width(), grid.height())));
+		}
+		Ok(grid)
+	}
 
-	fn part_one(&self) -> Result<String, String> {
-		let grid = self.parse()?;
-		let cheapest = cheapest_path(&grid, 0, 3);
-		Ok(cheapest.to_string())
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		let (cheapest, path) = cheapest_path(input, 0, 3).ok_or(Error::unsolvable("There is no path to the factory"))?;
+		render_path(input, &path, ctx);
+		Ok(cheapest.into())
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		let grid = self.parse()?;
-		let cheapest = cheapest_path(&grid, 4, 10);
-		Ok(cheapest.to_string())
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		let (min_moves, max_moves) = ultra_moves(ctx)?;
+		let (cheapest, path) = cheapest_path(input, min_moves, max_moves).ok_or(Error::unsolvable("There is no path to the factory"))?;
+		render_path(input, &path, ctx);
+		Ok(cheapest.into())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day17: part_one, part_two);
+}
@@ -1,10 +1,12 @@
 use Direction::{East, North, South, West};
 
-use crate::solution::Solution;
-use crate::util::Direction;
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Solution, Status};
+use crate::util::{Direction, IPoint2D};
 
+/// Day 18: Lavaduct Lagoon
 pub struct Day18 {
-	file: String,
+	source: InputSource,
 }
 
 #[derive(Debug)]
@@ -13,45 +15,54 @@ struct InputRow {
 	meters: usize,
 }
 
+/// The dig plan as it first appears, and as it is read once the hex codes are fixed.
+pub struct DigPlan {
+	plan: Vec<InputRow>,
+	fixed: Vec<InputRow>,
+}
+
 impl Day18 {
-	fn parse(&self) -> Result<Vec<InputRow>, String> {
-		self.read_file_as_string()?
-			.lines().into_iter()
-			.map(|line| {
+	fn parse_plan(input: &str) -> Result<Vec<InputRow>, Error> {
+		input
+			.lines()
+			.enumerate()
+			.map(|(i, line)| {
 				let mut parts = line.split_whitespace();
 				let direction = match parts.next() {
 					Some("R") => East,
 					Some("L") => West,
 					Some("U") => North,
 					Some("D") => South,
-					c => return Err(format!("Invalid direction: {:?}", c)),
+					c => return Err(Error::parse(format!("Invalid direction: {:?}", c)).at_line(i + 1)),
 				};
 
 				let meters = match parts.next() {
-					Some(m) => m.parse::<usize>().map_err(|e| format!("Invalid meters: {:?}", e))?,
-					None => return Err("Missing meters".to_string()),
+					Some(m) => m.parse::<usize>().map_err(|e| Error::parse(format!("Invalid meters: {e}")).at_line(i + 1))?,
+					None => return Err(Error::parse("Missing meters").at_line(i + 1)),
 				};
 
 				Ok(InputRow { direction, meters })
 			}).collect()
 	}
 
-	fn parse_fixed(&self) -> Result<Vec<InputRow>, String> {
-		self.read_file_as_string()?
-			.lines().into_iter()
-			.map(|line| {
-				let mut parts = line.split_whitespace().into_iter().skip(2);
-				let hex = parts.next().ok_or("Missing hex")?;
+	fn parse_fixed(input: &str) -> Result<Vec<InputRow>, Error> {
+		input
+			.lines()
+			.enumerate()
+			.map(|(i, line)| {
+				let mut parts = line.split_whitespace().skip(2);
+				let hex = parts.next().ok_or(Error::parse("Missing hex").at_line(i + 1))?;
 
-				let meters = usize::from_str_radix(&hex[2..7], 16)
-					.map_err(|e| format!("Invalid hex: {:?}", e))?;
+				let meters = hex.get(2..7)
+					.and_then(|digits| usize::from_str_radix(digits, 16).ok())
+					.ok_or(Error::parse(format!("Invalid hex: {hex}")).at_line(i + 1))?;
 
 				let direction = match hex.chars().nth(7) {
 					Some('0') => East,
 					Some('1') => South,
 					Some('2') => West,
 					Some('3') => North,
-					x => return Err(format!("Invalid direction: {:?}", x)),
+					x => return Err(Error::parse(format!("Invalid direction: {:?}", x)).at_line(i + 1)),
 				};
 
 				Ok(InputRow { direction, meters })
@@ -59,54 +70,97 @@ impl Day18 {
 	}
 }
 
-fn solve(inputs: Vec<InputRow>) -> Result<String, String> {
-	let mut current_pos = (0i64, 0i64);
+fn solve(inputs: &[InputRow]) -> Result<Answer, Error> {
+	if inputs.is_empty() { return Err(Error::invalid("The dig plan is empty")); }
+
+	let mut current_pos = IPoint2D::ZERO;
 	let mut points = Vec::new();
 	let mut boundary = 0;
 
 	for input in inputs {
 		let dist = input.meters as i64;
 		boundary += dist;
-		let (x, y) = current_pos;
-
-		current_pos = match input.direction {
-			North => (x, y - dist),
-			South => (x, y + dist),
-			East => (x + dist, y),
-			West => (x - dist, y),
-		};
+		current_pos = current_pos.move_dir(&input.direction, dist);
 		points.push(current_pos);
-
 	}
 
 	// Shoelace theorem
 	// Sum of (x1y2 - x2y1) / 2, where x1y1 is here and x2y2 is the next coordinate
 	let mut sum = 0i64;
 	for i in 0..points.len() - 1 {
-		let (x_1, y_1) = points[i];
-		let (x_2, y_2) = points[i + 1];
-		let p1 = x_1 * y_2;
-		let p2 = x_2 * y_1;
+		let (p_1, p_2) = (points[i], points[i + 1]);
+		let p1 = p_1.x * p_2.y;
+		let p2 = p_2.x * p_1.y;
 		sum += p1 - p2;
 	}
-	sum = sum / 2;
+	sum /= 2;
 
 	// Pick's theorem says -1, not +1, but I consistently get 2 too little.
 	// I don't know why, possibly it has something to do with overlapping points in my set,
 	// but this works and runs very fast, so I'm happy.
-	let picks = sum.clone() + (boundary / 2) + 1;
-	Ok(picks.to_string())
+	let picks = sum + (boundary / 2) + 1;
+	Ok(picks.into())
 }
 
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+",
+		part_one: Some("62"),
+		part_two: Some("952408144115"),
+		params: &[],
+	},
+];
+
 impl Solution for Day18 {
-	fn new(file: &str) -> Self { Day18 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
+	type Input = DigPlan;
+
+	fn new(source: InputSource) -> Self { Day18 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 18, year: 2023, title: "Lavaduct Lagoon" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Solved] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		let plan = Day18::parse_plan(input)?;
+		let fixed = Day18::parse_fixed(input)?;
+		Ok(DigPlan { plan, fixed })
+	}
 
-	fn part_one(&self) -> Result<String, String> {
-		solve(self.parse()?)
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		solve(&input.plan)
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		solve(self.parse_fixed()?)
+	fn part_two(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		solve(&input.fixed)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day18: part_one, part_two);
+}
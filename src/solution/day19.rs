@@ -3,13 +3,15 @@ use std::ops::RangeInclusive;
 
 use regex::Regex;
 
-use crate::solution::Solution;
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Solution, Status};
 
+/// Day 19: Aplenty
 pub struct Day19 {
-	file: String,
+	source: InputSource,
 }
 
-struct Input {
+pub struct Input {
 	workflows: HashMap<String, Vec<Rule>>,
 	parts: Vec<Part>,
 }
@@ -62,70 +64,51 @@ impl Condition {
 }
 
 impl Day19 {
-	fn parse(&self) -> Result<Input, String> {
-		let file = self.read_file_as_string()?;
-		let mut file_iter = file.split("\n\n").into_iter();
-		let workflows = file_iter.next().ok_or("No workflows")?.lines()
-			.map(|line| self.parse_workflow(line))
-			.collect::<Result<HashMap<String, Vec<Rule>>, String>>()?;
-
-		let parts = file_iter.next().ok_or("No parts")?.lines()
-			.map(|p| self.parse_part(p))
-			.collect::<Result<Vec<Part>, String>>()?;
-
-		Ok(Input { parts, workflows })
-	}
-
-	fn parse_part(&self, line: &str) -> Result<Part, String> {
-		let re = Regex::new(r"\{x=(\d+),m=(\d+),a=(\d+),s=(\d+)}").map_err(|_| "Failed to create regex")?;
-		if let Some(caps) = re.captures(line) {
-			let x = caps.get(1).ok_or("No x")?.as_str().parse::<usize>().map_err(|_| "Failed to parse x")?;
-			let m = caps.get(2).ok_or("No m")?.as_str().parse::<usize>().map_err(|_| "Failed to parse m")?;
-			let a = caps.get(3).ok_or("No a")?.as_str().parse::<usize>().map_err(|_| "Failed to parse a")?;
-			let s = caps.get(4).ok_or("No s")?.as_str().parse::<usize>().map_err(|_| "Failed to parse s")?;
-			Ok(Part { x, m, a, s })
-		} else {
-			Err(format!("Failed to parse part {line}"))
-		}
+	fn parse_part(line: &str) -> Result<Part, Error> {
+		let re = Regex::new(r"\{x=(\d+),m=(\d+),a=(\d+),s=(\d+)}")
+			.map_err(|e| Error::unsolvable(format!("Failed to create regex: {e}")))?;
+		let caps = re.captures(line).ok_or(Error::parse(format!("Failed to parse part {line}")))?;
+		let rating = |i: usize| caps[i].parse::<usize>()
+			.map_err(|_| Error::parse(format!("Failed to parse rating {}", &caps[i])).at_column(caps.get(i).map_or(0, |c| c.start()) + 1));
+
+		Ok(Part { x: rating(1)?, m: rating(2)?, a: rating(3)?, s: rating(4)? })
 	}
 
 	fn parse_workflow(
-		&self,
 		line: &str,
-	) -> Result<(String, Vec<Rule>), String> {
-		let re = Regex::new(r"(\w+)\{([^}]+)}").map_err(|_| "Failed to create regex")?;
-		if let Some(caps) = re.captures(line) {
-			let name = caps.get(1).ok_or("No name")?.as_str().to_string();
-
-			let rules = caps.get(2).ok_or("No rules")?.as_str()
-				.split(",")
-				.map(|cond| self.parse_rule(cond))
-				.collect::<Result<Vec<Rule>, String>>()?;
-
-			Ok((name, rules))
-		} else {
-			Err("No match".to_string())
-		}
+	) -> Result<(String, Vec<Rule>), Error> {
+		let re = Regex::new(r"(\w+)\{([^}]+)}")
+			.map_err(|e| Error::unsolvable(format!("Failed to create regex: {e}")))?;
+		let caps = re.captures(line).ok_or(Error::parse(format!("Failed to parse workflow {line}")))?;
+		let name = caps[1].to_string();
+
+		let rules = caps[2]
+			.split(',')
+			.map(Day19::parse_rule)
+			.collect::<Result<Vec<Rule>, Error>>()?;
+
+		Ok((name, rules))
 	}
 
 	fn parse_rule(
-		&self,
 		s: &str,
-	) -> Result<Rule, String> {
-		let split = s.split(":").collect::<Vec<&str>>();
+	) -> Result<Rule, Error> {
+		let split = s.split(':').collect::<Vec<&str>>();
 		let mut iter = split.into_iter().rev();
 
-		let then = iter.next().ok_or("No target for rule")?.to_string();
+		let then = iter.next().ok_or(Error::parse("No target for rule"))?.to_string();
 		let condition = match iter.next() {
 			Some(s) => {
 				let mut chars = s.chars();
-				let target = chars.next().ok_or(format!("No target for rule {s}"))?;
+				let target = chars.next().ok_or(Error::parse(format!("No target for rule {s}")))?;
 
-				let num = s[2..].parse::<usize>().map_err(|_| format!("Failed to parse number in {s}"))?;
+				let num = s.get(2..)
+					.and_then(|num| num.parse::<usize>().ok())
+					.ok_or(Error::parse(format!("Failed to parse number in {s}")))?;
 				let bounds = match chars.next() {
 					Some('>') => (num + 1)..=4000,
-					Some('<') => 0..=(num - 1),
-					_ => return Err(format!("Invalid target in {s}")),
+					Some('<') if num > 0 => 0..=(num - 1),
+					_ => return Err(Error::parse(format!("Invalid target in {s}"))),
 				};
 
 				Condition::new(target, bounds)
@@ -137,30 +120,82 @@ impl Day19 {
 	}
 }
 
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+",
+		part_one: Some("19114"),
+		part_two: Some("167409079868000"),
+		params: &[],
+	},
+];
+
 impl Solution for Day19 {
-	fn new(file: &str) -> Self { Day19 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
+	type Input = Input;
+
+	fn new(source: InputSource) -> Self { Day19 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 19, year: 2023, title: "Aplenty" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Unimplemented] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		let mut file_iter = input.split("\n\n");
+		let workflow_lines = file_iter.next().ok_or(Error::invalid("No workflows"))?.lines().collect::<Vec<&str>>();
+		let workflows = workflow_lines.iter()
+			.enumerate()
+			.map(|(i, line)| Day19::parse_workflow(line).map_err(|e| e.at_line(i + 1)))
+			.collect::<Result<HashMap<String, Vec<Rule>>, Error>>()?;
+
+		let first_part_line = workflow_lines.len() + 2;
+		let parts = file_iter.next().ok_or(Error::invalid("No parts"))?.lines()
+			.enumerate()
+			.map(|(i, line)| Day19::parse_part(line).map_err(|e| e.at_line(first_part_line + i)))
+			.collect::<Result<Vec<Part>, Error>>()?;
+
+		Ok(Input { parts, workflows })
+	}
 
-	fn part_one(&self) -> Result<String, String> {
-		let input = self.parse()?;
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
 		let mut score = 0usize;
 
-		for part in input.parts {
+		for part in &input.parts {
 			let mut next = "in".to_string();
 			while next != "A" && next != "R" {
-				let workflow = input.workflows.get(&next).ok_or(format!("No workflow for {next}"))?;
-				let evaluation = evaluate(&part, workflow)?.ok_or(format!("No evaluation for {part:?}"))?;
+				let workflow = input.workflows.get(&next).ok_or(Error::invalid(format!("No workflow for {next}")))?;
+				let evaluation = evaluate(part, workflow)?.ok_or(Error::invalid(format!("No evaluation for {part:?}")))?;
 				next = evaluation;
 			}
 
 			if next == "A" { score += part.x + part.a + part.m + part.s; }
 		}
 
-		Ok(score.to_string())
+		Ok(score.into())
 	}
 }
 
-fn evaluate(part: &Part, rules: &Vec<Rule>) -> Result<Option<String>, String> {
+fn evaluate(part: &Part, rules: &[Rule]) -> Result<Option<String>, Error> {
 	for rule in rules {
 		let out = if rule.condition.test(part) { Some(&rule.then) } else { None };
 		if let Some(x) = out { return Ok(Some(x.to_string())); }
@@ -168,3 +203,10 @@ fn evaluate(part: &Part, rules: &Vec<Rule>) -> Result<Option<String>, String> {
 
 	Ok(None)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day19: part_one);
+}
@@ -1,12 +1,15 @@
 use std::collections::{HashMap, VecDeque};
 use ModuleType::{Broadcaster, Conjunction, FlipFlop};
-use crate::solution::Solution;
+use crate::{debug, trace};
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Solution, Status};
 
+/// Day 20: Pulse Propagation
 pub struct Day20 {
-	file: String,
+	source: InputSource,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 enum ModuleType {
 	Broadcaster,
 	FlipFlop(bool),
@@ -16,20 +19,56 @@ enum ModuleType {
 #[derive(Debug, PartialEq, Clone)]
 enum Pulse { High, Low }
 
-#[derive(Debug)]
-struct Module {
+#[derive(Debug, Clone)]
+pub struct Module {
 	name: String,
 	module_type: ModuleType,
 	targets: Vec<String>,
 }
 
-impl Day20 {
-	fn parse(&self) -> Result<HashMap<String, Module>, String> {
-		self.read_file_as_string()?.lines()
-			.map(|line| {
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+",
+		part_one: Some("32000000"),
+		part_two: None,
+		params: &[],
+	},
+	Example {
+		input: "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+",
+		part_one: Some("11687500"),
+		part_two: None,
+		params: &[],
+	},
+];
+
+impl Solution for Day20 {
+	type Input = HashMap<String, Module>;
+
+	fn new(source: InputSource) -> Self { Day20 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 20, year: 2023, title: "Pulse Propagation" } }
+	fn status() -> [Status; 2] { [Status::Unimplemented, Status::Unimplemented] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		input.lines()
+			.enumerate()
+			.map(|(i, line)| {
 				let mut parts = line.split(" -> ");
-				let name = parts.next().ok_or("No name")?.to_string();
-				let targets: Vec<String> = parts.next().ok_or("No targets")?
+				let name = parts.next().ok_or(Error::parse("No name").at_line(i + 1))?.to_string();
+				let targets: Vec<String> = parts.next().ok_or(Error::parse("No targets").at_line(i + 1))?
 					.split(", ")
 					.map(|s| s.to_string())
 					.collect::<Vec<String>>();
@@ -38,38 +77,39 @@ impl Day20 {
 					Some('b') => { Ok((name, Broadcaster)) }
 					Some('%') => { Ok((name[1..].to_string(), FlipFlop(false))) }
 					Some('&') => { Ok((name[1..].to_string(), Conjunction)) }
-					c => { Err(format!("Failed to parse module name: {line}, char was {c:?}")) }
+					c => { Err(Error::parse(format!("Failed to parse module name: {line}, char was {c:?}")).at_line(i + 1).at_column(1)) }
 				}?;
 
 				let module = Module { name: name.clone(), module_type, targets };
 				Ok((name, module))
-			}).collect::<Result<HashMap<String, Module>, String>>()
+			}).collect::<Result<HashMap<String, Module>, Error>>()
 	}
-}
-
-impl Solution for Day20 {
-	fn new(file: &str) -> Self { Day20 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
 
-	fn part_one(&self) -> Result<String, String> {
-		let mut modules = self.parse()?;
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		let mut modules = input.clone();
 		let mut highs = 0usize;
 		let mut lows = 0usize;
 
-		let broadcaster = modules["broadcaster"].targets.iter()
+		let broadcaster = modules.get("broadcaster")
+			.ok_or(Error::invalid("No broadcaster module"))?
+			.targets.iter()
 			.map(|target| ("broadcaster".to_string(), Pulse::Low, target.clone()))
 			.collect::<Vec<(String, Pulse, String)>>();
 		let mut queue = VecDeque::from(broadcaster);
 
 		while let Some((source, pulse, target)) = queue.pop_front() {
-			println!("{source} -{:?}-> {:?}", &pulse, &target);
+			trace!("{source} -{pulse:?}-> {target}");
 			if target == "output" { continue; }
 
 			if pulse == Pulse::High { highs += 1; } else { lows += 1; }
-			let module = modules.get_mut(&target).ok_or(format!("No module named {target}"))?;
+			let module = modules.get_mut(&target).ok_or(Error::invalid(format!("No module named {target}")))?;
 
 			let send = match &module.module_type {
-				Broadcaster => Err("Broadcaster cannot be a target".to_string())?,
+				Broadcaster => Err(Error::invalid("Broadcaster cannot be a target"))?,
 
 				FlipFlop(is_on) => {
 					if pulse == Pulse::Low {
@@ -100,9 +140,8 @@ impl Solution for Day20 {
 			}
 		}
 
-		println!("highs: {highs}");
-		println!("lows: {lows}");
+		debug!("highs: {highs}, lows: {lows}");
 
-		Err("Not implemented".to_string())
+		Err(Error::NotImplemented)
 	}
 }
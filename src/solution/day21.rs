@@ -1,58 +1,98 @@
 use std::collections::{HashSet, VecDeque};
-use Direction::{East, North, South, West};
-use crate::solution::Solution;
-use crate::util::{Direction, Matrix2DExt, Point2D, Point2DExt};
+use crate::error::Error;
+use crate::solution::{Answer, Context, DayInfo, Example, InputSource, Param, Solution, Status};
+use crate::util::{Cell, Colour, Grid, Point2D};
 
+/// Day 21: Step Counter
 pub struct Day21 {
-	file: String,
+	source: InputSource,
 }
 
-impl Day21 {
-	fn parse(&self) -> Result<(Point2D, HashSet<Point2D>), String> {
-		let lines = self.read_file_as_string()?.lines()
-			.map(|line| line.chars().collect::<Vec<char>>())
-			.collect::<Vec<Vec<char>>>();
-		let mut start = None;
-		let mut rocks = HashSet::new();
-
-		for x in lines.x_range() {
-			for y in lines.y_range() {
-				let char = lines.get_xy(x, y).ok_or("Failed to get char")?;
-				if char == &'S' {
-					start = Some((x, y));
-				} else if char == &'#' {
-					rocks.insert((x, y));
-				}
-			}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Plot {
+	Garden,
+	Rock,
+}
+
+impl Cell for Plot {
+	fn from_char(c: char) -> Option<Self> {
+		match c {
+			'.' | 'S' => Some(Plot::Garden),
+			'#' => Some(Plot::Rock),
+			_ => None,
 		}
+	}
 
-		let start = start.ok_or("No start found")?;
-		Ok((start, rocks))
+	fn to_char(&self) -> char {
+		match self {
+			Plot::Garden => '.',
+			Plot::Rock => '#',
+		}
 	}
 }
 
+const PARAMS: &[Param] = &[
+	Param { name: "steps", description: "steps the elf takes in part one", default: 64, min: 1 },
+];
+
+const EXAMPLES: &[Example] = &[
+	Example {
+		input: "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+",
+		part_one: Some("16"),
+		part_two: None,
+		params: &[("steps", 6)],
+	},
+];
+
 impl Solution for Day21 {
-	fn new(file: &str) -> Self { Day21 { file: file.to_string() } }
-	fn get_file_name(&self) -> &str { &self.file }
+	/// Where the elf starts, and the map of the garden.
+	type Input = (Point2D, Grid<Plot>);
 
-	fn part_one(&self) -> Result<String, String> {
-		let (start, rocks) = self.parse()?;
-		let directions = vec![North, South, East, West];
-		let mut queue = VecDeque::from(vec![(0usize, start)]);
+	fn new(source: InputSource) -> Self { Day21 { source } }
+	fn get_source(&self) -> &InputSource { &self.source }
+	fn info() -> DayInfo { DayInfo { day: 21, year: 2023, title: "Step Counter" } }
+	fn status() -> [Status; 2] { [Status::Solved, Status::Unimplemented] }
+	fn examples() -> &'static [Example] { EXAMPLES }
+	fn params() -> &'static [Param] { PARAMS }
+
+	fn parse(input: &str) -> Result<Self::Input, Error> {
+		let (garden, starts) = Grid::parse_with_markers(input, 'S')?;
+		let start = starts.first().copied().ok_or(Error::invalid("No start found"))?;
+		Ok((start, garden))
+	}
+
+	fn part_one(
+		&self,
+		input: &Self::Input,
+		ctx: &Context,
+	) -> Result<Answer, Error> {
+		let (start, garden) = input;
+		let mut queue = VecDeque::from(vec![(0usize, *start)]);
 		let mut done = HashSet::new();
 
-		let done_steps = 64usize;
+		let done_steps = ctx.param("steps")?;
 		let mod_2 = done_steps % 2;
 
 		while let Some((steps, pos)) = queue.pop_front() {
-			let new_positions = directions.iter()
-				.filter_map(|dir| pos.move_dir(dir));
+			let new_positions = garden.neighbours(pos)
+				// Only garden plots, the rocks can't be walked on
+				.filter(|&new_pos| garden[new_pos] == Plot::Garden);
 
 			let new_steps = steps + 1;
 			for new_pos in new_positions {
-				if rocks.contains(&new_pos) {
-					continue;
-				} else if new_steps == done_steps {
+				if new_steps == done_steps {
 					done.insert(new_pos);
 					continue;
 				}
@@ -66,6 +106,16 @@ impl Solution for Day21 {
 			}
 		}
 
-		Ok(done.len().to_string())
+		ctx.render("Reachable garden plots", || garden.render()
+			.mark(done.iter().copied(), 'O', Colour::Green)
+			.highlight([*start], Colour::Red));
+		Ok(done.len().into())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	example_tests!(Day21: part_one);
+}
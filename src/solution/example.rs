@@ -0,0 +1,58 @@
+use crate::error::Error;
+use crate::solution::{Param, Params};
+#[cfg(test)]
+use crate::solution::Solution;
+
+/// An example input from the puzzle text, together with the answers the puzzle gives for it.
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+	/// The example input, exactly as in the puzzle text.
+	pub input: &'static str,
+	/// The answer to part one, if the puzzle gives one for this example.
+	pub part_one: Option<&'static str>,
+	/// The answer to part two, if the puzzle gives one for this example.
+	pub part_two: Option<&'static str>,
+	/// Parameters the puzzle uses differently for this example, see [crate::solution::Solution::params].
+	pub params: &'static [(&'static str, usize)],
+}
+
+impl Example {
+	/// The expected answer to `part`, if there is one.
+	pub fn expected(&self, part: i32) -> Option<&'static str> {
+		match part {
+			1 => self.part_one,
+			2 => self.part_two,
+			_ => None,
+		}
+	}
+
+	/// The defaults of `schema`, with the parameters of this example applied.
+	pub fn params(
+		&self,
+		schema: &[Param],
+	) -> Result<Params, Error> {
+		let mut params = Params::defaults(schema);
+		for (name, value) in self.params {
+			params.set(schema, name, &value.to_string())?;
+		}
+
+		Ok(params)
+	}
+}
+
+/// Asserts that solution `S` finds the expected answer to `part` for each of its examples.
+#[cfg(test)]
+pub(crate) fn assert_examples<S: Solution>(part: i32) {
+	let examples = S::examples().iter()
+		.enumerate()
+		.filter_map(|(i, example)| example.expected(part).map(|expected| (i + 1, example, expected)))
+		.collect::<Vec<_>>();
+	assert!(!examples.is_empty(), "Day {} has no examples for part {part}", S::info().day);
+
+	for (number, example, expected) in examples {
+		let answer = example.params(S::params())
+			.and_then(|params| S::solve_with(part, example.input, &params))
+			.unwrap_or_else(|e| panic!("Example {number} of part {part} failed: {e}"));
+		assert_eq!(answer.to_string(), expected, "Wrong answer to example {number} of part {part}");
+	}
+}
@@ -0,0 +1,53 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::{self, Read};
+use crate::error::Error;
+
+/// Where a solution reads its puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+	/// A file, relative to the working directory.
+	File(String),
+	/// Everything piped into the program.
+	Stdin,
+	/// Input that is already in memory, e.g. an example from the puzzle text.
+	Text(String),
+}
+
+impl InputSource {
+	/// Reads from the file at `path`, or from stdin if `path` is `-`.
+	pub fn from_arg(path: &str) -> InputSource {
+		match path {
+			"-" => InputSource::Stdin,
+			path => InputSource::File(path.to_string()),
+		}
+	}
+
+	/// See [InputSource::Text].
+	pub fn text<S: Into<String>>(text: S) -> InputSource {
+		InputSource::Text(text.into())
+	}
+
+	/// The whole input, read from wherever it lives.
+	pub fn read(&self) -> Result<String, Error> {
+		match self {
+			InputSource::File(path) => fs::read_to_string(path).map_err(|e| Error::io(path, e)),
+			InputSource::Stdin => {
+				let mut input = String::new();
+				io::stdin().read_to_string(&mut input).map_err(|e| Error::io("stdin", e))?;
+				Ok(input)
+			}
+			InputSource::Text(text) => Ok(text.clone()),
+		}
+	}
+}
+
+impl Display for InputSource {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			InputSource::File(path) => write!(f, "{path}"),
+			InputSource::Stdin => write!(f, "stdin"),
+			InputSource::Text(_) => write!(f, "text"),
+		}
+	}
+}
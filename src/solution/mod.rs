@@ -1,46 +1,99 @@
+//! The solution to every day, and the registry used to look them up.
+
+#[allow(clippy::module_inception)]
 mod solution;
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
+mod answer;
+mod context;
+mod example;
+mod input;
+mod params;
+mod progress;
+mod registry;
+mod status;
+mod variant;
 
+pub use answer::Answer;
+pub use context::{CancelToken, Context};
+pub use example::Example;
+pub use input::InputSource;
+pub use params::{Param, Params};
+pub use progress::Progress;
+pub use solution::DayInfo;
+pub use solution::DynSolution;
 pub use solution::Solution;
-pub use solution::PlaceholderSolution;
-pub use day01::Day01;
-pub use day02::Day02;
-pub use day03::Day03;
-pub use day04::Day04;
-pub use day05::Day05;
-pub use day06::Day06;
-pub use day07::Day07;
-pub use day08::Day08;
-pub use day09::Day09;
-pub use day10::Day10;
-pub use day11::Day11;
-pub use day12::Day12;
-pub use day13::Day13;
-pub use day14::Day14;
-pub use day15::Day15;
-pub use day16::Day16;
-pub use day17::Day17;
-pub use day18::Day18;
-pub use day19::Day19;
-pub use day20::Day20;
-pub use day21::Day21;
+pub use registry::Registration;
+pub use status::Status;
+pub use variant::{Variant, VariantInfo, DEFAULT_VARIANT};
+
+#[cfg(test)]
+pub(crate) use example::assert_examples;
+
+/// Generates a test per listed part, checking the answers to the examples of a solution.
+/// An attribute in front of the solution, like `#[ignore]`, is put on every test.
+#[cfg(test)]
+macro_rules! example_tests {
+	($solution:ident: $($part:ident),+ $(,)?) => {
+		$(
+			#[test]
+			fn $part() {
+				$crate::solution::assert_examples::<$solution>(example_tests!(@number $part));
+			}
+		)+
+	};
+	(#[$attribute:meta] $solution:ident: $($part:ident),+ $(,)?) => {
+		$(
+			#[test]
+			#[$attribute]
+			fn $part() {
+				$crate::solution::assert_examples::<$solution>(example_tests!(@number $part));
+			}
+		)+
+	};
+	(@number part_one) => { 1 };
+	(@number part_two) => { 2 };
+}
+
+/// Declares the module of every day and adds its solution to the registry,
+/// so that a new day only has to be listed here once.
+macro_rules! register {
+	($($module:ident::$solution:ident),* $(,)?) => {
+		$(mod $module;)*
+		$(pub use $module::$solution;)*
+
+		/// Every registered solution, ordered by day.
+		pub fn registry() -> Vec<Registration> {
+			let mut registrations = vec![$(Registration::of::<$solution>()),*];
+			registrations.sort_by_key(|r| r.info.day);
+			registrations
+		}
+	};
+}
+
+register! {
+	day01::Day01,
+	day02::Day02,
+	day03::Day03,
+	day04::Day04,
+	day05::Day05,
+	day06::Day06,
+	day07::Day07,
+	day08::Day08,
+	day09::Day09,
+	day10::Day10,
+	day11::Day11,
+	day12::Day12,
+	day13::Day13,
+	day14::Day14,
+	day15::Day15,
+	day16::Day16,
+	day17::Day17,
+	day18::Day18,
+	day19::Day19,
+	day20::Day20,
+	day21::Day21,
+}
+
+/// The registered solution of `day`, if there is one.
+pub fn find(day: i32) -> Option<Registration> {
+	registry().into_iter().find(|r| r.info.day == day)
+}
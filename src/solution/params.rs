@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use crate::error::Error;
+
+/// A constant from the puzzle text that a solution lets the user change.
+#[derive(Debug, Clone, Copy)]
+pub struct Param {
+	/// The key to set it with, as in `--param key=value`.
+	pub name: &'static str,
+	/// What the parameter changes.
+	pub description: &'static str,
+	/// The value the puzzle asks for.
+	pub default: usize,
+	/// The smallest value the solution can handle.
+	pub min: usize,
+}
+
+impl Display for Param {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self.min {
+			0 => write!(f, "{}={} ({})", self.name, self.default, self.description),
+			min => write!(f, "{}={} ({}, at least {min})", self.name, self.default, self.description),
+		}
+	}
+}
+
+/// The value of every parameter of a solution.
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+	values: BTreeMap<&'static str, usize>,
+}
+
+impl Params {
+	/// Every parameter in `schema` set to its default.
+	pub fn defaults(schema: &[Param]) -> Params {
+		Params { values: schema.iter().map(|param| (param.name, param.default)).collect() }
+	}
+
+	/// The defaults of `schema`, overridden by `key=value` arguments.
+	pub fn parse<S: AsRef<str>>(
+		schema: &[Param],
+		args: &[S],
+	) -> Result<Params, Error> {
+		let mut params = Params::defaults(schema);
+		for arg in args {
+			let (name, value) = arg.as_ref().split_once('=')
+				.ok_or(Error::invalid(format!("Expected a parameter as key=value, found '{}'", arg.as_ref())))?;
+			params.set(schema, name.trim(), value.trim())?;
+		}
+
+		Ok(params)
+	}
+
+	/// Sets the parameter `name` of `schema` to `value`, if it is a valid value for it.
+	pub fn set(
+		&mut self,
+		schema: &[Param],
+		name: &str,
+		value: &str,
+	) -> Result<(), Error> {
+		let param = schema.iter().find(|param| param.name == name).ok_or_else(|| {
+			let names = schema.iter().map(|param| param.name).collect::<Vec<&str>>();
+			if names.is_empty() {
+				Error::invalid(format!("Unknown parameter '{name}', this day has none"))
+			} else {
+				Error::invalid(format!("Unknown parameter '{name}', expected one of {}", names.join(", ")))
+			}
+		})?;
+
+		let value = value.parse::<usize>()
+			.map_err(|_| Error::invalid(format!("Parameter {name} must be a number, found '{value}'")))?;
+		if value < param.min {
+			return Err(Error::invalid(format!("Parameter {name} must be at least {}, found {value}", param.min)));
+		}
+
+		self.values.insert(param.name, value);
+		Ok(())
+	}
+
+	/// The value of the parameter `name`.
+	pub fn get(&self, name: &str) -> Result<usize, Error> {
+		self.values.get(name)
+			.copied()
+			.ok_or(Error::invalid(format!("Unknown parameter '{name}'")))
+	}
+}
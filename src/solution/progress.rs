@@ -0,0 +1,88 @@
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+const BAR_WIDTH: usize = 30;
+const REDRAW_EVERY: Duration = Duration::from_millis(100);
+
+/// Tracks how much of a long running part is done, drawing a bar with an ETA on stderr.
+/// Safe to share between rayon threads. Get one from [crate::solution::Context::progress].
+///
+/// Nothing is drawn unless the runner asked for it and stderr is a terminal,
+/// so piped output stays clean. The bar is cleared again when the progress is dropped.
+#[derive(Debug)]
+pub struct Progress {
+	label: String,
+	total: usize,
+	done: AtomicUsize,
+	start: Instant,
+	/// Nanoseconds after `start` the bar was last drawn at.
+	drawn_at: AtomicU64,
+	visible: bool,
+}
+
+impl Progress {
+	pub(crate) fn new(
+		label: &str,
+		total: usize,
+		visible: bool,
+	) -> Progress {
+		Progress {
+			label: label.to_string(),
+			total,
+			done: AtomicUsize::new(0),
+			start: Instant::now(),
+			drawn_at: AtomicU64::new(0),
+			visible: visible && std::io::stderr().is_terminal(),
+		}
+	}
+
+	/// Marks one more unit of work as done.
+	pub fn inc(&self) { self.add(1) }
+
+	/// Marks `count` more units of work as done.
+	pub fn add(&self, count: usize) {
+		let done = self.done.fetch_add(count, Ordering::Relaxed) + count;
+		if !self.visible { return; }
+
+		// Only one thread gets to draw, and at most every REDRAW_EVERY
+		let now = self.start.elapsed().as_nanos() as u64;
+		let drawn_at = self.drawn_at.load(Ordering::Relaxed);
+		let due = now.saturating_sub(drawn_at) >= REDRAW_EVERY.as_nanos() as u64 || done >= self.total;
+		if due && self.drawn_at.compare_exchange(drawn_at, now, Ordering::Relaxed, Ordering::Relaxed).is_ok() {
+			self.draw(done);
+		}
+	}
+
+	/// How many units of work are done so far.
+	pub fn done(&self) -> usize { self.done.load(Ordering::Relaxed) }
+
+	fn draw(&self, done: usize) {
+		let done = done.min(self.total);
+		let filled = (done * BAR_WIDTH).checked_div(self.total).unwrap_or(BAR_WIDTH);
+		let percent = (done * 100).checked_div(self.total).unwrap_or(100);
+		let eta = match done {
+			0 => "?".to_string(),
+			_ => format!("{:.0?}", self.start.elapsed().mul_f64((self.total - done) as f64 / done as f64)),
+		};
+
+		let mut stderr = std::io::stderr().lock();
+		let _ = write!(
+			stderr,
+			"\r\x1B[2K{} [{}{}] {done}/{} {percent}% ETA {eta}",
+			self.label,
+			"#".repeat(filled),
+			"-".repeat(BAR_WIDTH - filled),
+			self.total,
+		);
+		let _ = stderr.flush();
+	}
+}
+
+impl Drop for Progress {
+	fn drop(&mut self) {
+		if self.visible {
+			let _ = write!(std::io::stderr(), "\r\x1B[2K");
+		}
+	}
+}
@@ -0,0 +1,56 @@
+use crate::error::Error;
+use crate::solution::{Answer, DayInfo, DynSolution, Example, InputSource, Param, Solution, Status, VariantInfo, DEFAULT_VARIANT};
+
+/// A registered day, able to build its solution for any input.
+pub struct Registration {
+	/// Which puzzle the day solves.
+	pub info: DayInfo,
+	/// How far along both parts are, see [Solution::status].
+	pub status: [Status; 2],
+	/// The examples from the puzzle text, see [Solution::examples].
+	pub examples: &'static [Example],
+	/// The parameters the day can be tuned with, see [Solution::params].
+	pub params: &'static [Param],
+	/// The other implementations of its parts, see [Solution::variants].
+	pub variants: Vec<VariantInfo>,
+	build: fn(InputSource) -> Box<dyn DynSolution>,
+	solve: fn(i32, &str) -> Result<Answer, Error>,
+}
+
+impl Registration {
+	/// The registration of solution `S`.
+	pub fn of<S: Solution + Send + Sync + 'static>() -> Self {
+		Registration {
+			info: S::info(),
+			status: S::status(),
+			examples: S::examples(),
+			params: S::params(),
+			variants: S::variants().iter().map(VariantInfo::from).collect(),
+			build: |source| Box::new(S::new(source)),
+			solve: S::solve,
+		}
+	}
+
+	/// The name of every variant of `part`, starting with [DEFAULT_VARIANT].
+	pub fn variant_names(&self, part: i32) -> Vec<&'static str> {
+		let others = self.variants.iter().filter(|v| v.part == part).map(|v| v.name);
+		std::iter::once(DEFAULT_VARIANT).chain(others).collect()
+	}
+
+	/// See [Solution::solve].
+	pub fn solve(
+		&self,
+		part: i32,
+		input: &str,
+	) -> Result<Answer, Error> {
+		(self.solve)(part, input)
+	}
+
+	/// Creates the solution for the input found in `source`.
+	pub fn build(
+		&self,
+		source: InputSource,
+	) -> Box<dyn DynSolution> {
+		(self.build)(source)
+	}
+}
@@ -1,42 +1,153 @@
-use std::fs;
+use std::any::Any;
 use std::str::FromStr;
+use crate::error::Error;
+use crate::solution::{Answer, Context, Example, InputSource, Param, Params, Status, Variant, DEFAULT_VARIANT};
 
+/// Which puzzle a solution belongs to.
+#[derive(Debug, Clone, Copy)]
+pub struct DayInfo {
+	/// The day of December the puzzle was released on.
+	pub day: i32,
+	/// The Advent of Code edition.
+	pub year: i32,
+	/// The title of the puzzle.
+	pub title: &'static str,
+}
+
+/// The solution to one day's puzzle.
 pub trait Solution {
-	fn new(filename: &str) -> Self where Self: Sized;
-	fn get_file_name(&self) -> &str;
-	fn get_file_path(&self) -> String { format!("{}", self.get_file_name()) }
+	/// The parsed puzzle input, shared by both parts.
+	type Input: Send + Sync + 'static;
+
+	/// Creates the solution for the input found in `source`.
+	fn new(source: InputSource) -> Self where Self: Sized;
+	/// Where the input is read from.
+	fn get_source(&self) -> &InputSource;
+	/// Which puzzle this is the solution to.
+	fn info() -> DayInfo where Self: Sized;
+	/// How far along both parts are, in order.
+	fn status() -> [Status; 2] where Self: Sized;
 
-	fn part_one(&self) -> Result<String, String> {
-		Err("Solution not yet implemented!".to_string())
+	/// The examples from the puzzle text, with their expected answers.
+	fn examples() -> &'static [Example] where Self: Sized { &[] }
+	/// The constants from the puzzle text that can be changed through [Context::param].
+	fn params() -> &'static [Param] where Self: Sized { &[] }
+	/// Other implementations of the parts, next to [Solution::part_one] and [Solution::part_two].
+	fn variants() -> &'static [Variant<Self>] where Self: Sized + 'static { &[] }
+
+	/// Turns the raw puzzle input into something both parts can use.
+	fn parse(input: &str) -> Result<Self::Input, Error>;
+
+	/// Solves the first part of the puzzle.
+	fn part_one(
+		&self,
+		_input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		Err(Error::NotImplemented)
 	}
 
-	fn part_two(&self) -> Result<String, String> {
-		Err("Solution not yet implemented!".to_string())
+	/// Solves the second part of the puzzle.
+	fn part_two(
+		&self,
+		_input: &Self::Input,
+		_ctx: &Context,
+	) -> Result<Answer, Error> {
+		Err(Error::NotImplemented)
 	}
 
-	fn read_file_as_string(&self) -> Result<String, String> {
-		match fs::read_to_string(self.get_file_path()) {
-			Ok(s) => Ok(s),
-			Err(s) => Err(format!("Failed to read file: {s}")),
+	/// Parses `input` and solves one of its parts, without touching the file system.
+	fn solve(
+		part: i32,
+		input: &str,
+	) -> Result<Answer, Error> where Self: Sized {
+		Self::solve_with(part, input, &Params::defaults(Self::params()))
+	}
+
+	/// Like [Solution::solve], with `params` instead of the defaults.
+	fn solve_with(
+		part: i32,
+		input: &str,
+		params: &Params,
+	) -> Result<Answer, Error> where Self: Sized {
+		let solution = Self::new(InputSource::text(input));
+		let input = Self::parse(&solution.read_input()?)?;
+		let ctx = Context::with_params(params.clone());
+		match part {
+			1 => solution.part_one(&input, &ctx),
+			2 => solution.part_two(&input, &ctx),
+			_ => Err(Error::invalid(format!("There is no part {part}"))),
 		}
 	}
+
+	/// Reads the raw input this solution was created for.
+	fn read_input(&self) -> Result<String, Error> {
+		self.get_source().read()
+	}
+}
+
+/// Object safe counterpart of [Solution], used by the runner to handle every day
+/// the same way regardless of what its parsed input looks like.
+pub trait DynSolution: Send + Sync {
+	/// See [Solution::read_input].
+	fn read_input(&self) -> Result<String, Error>;
+	/// See [Solution::parse]. The result can only be passed back to the same solution.
+	fn parse(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>, Error>;
+	/// See [Solution::part_one].
+	fn part_one(&self, input: &dyn Any, ctx: &Context) -> Result<Answer, Error>;
+	/// See [Solution::part_two].
+	fn part_two(&self, input: &dyn Any, ctx: &Context) -> Result<Answer, Error>;
+	/// Solves `part` with the variant called `variant`, see [Solution::variants].
+	fn part_variant(&self, part: i32, variant: &str, input: &dyn Any, ctx: &Context) -> Result<Answer, Error>;
 }
 
-pub struct PlaceholderSolution {}
+impl<S: Solution + Send + Sync + 'static> DynSolution for S {
+	fn read_input(&self) -> Result<String, Error> { Solution::read_input(self) }
+
+	fn parse(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>, Error> {
+		Ok(Box::new(S::parse(input)?))
+	}
+
+	fn part_one(&self, input: &dyn Any, ctx: &Context) -> Result<Answer, Error> {
+		Solution::part_one(self, downcast::<S>(input)?, ctx)
+	}
+
+	fn part_two(&self, input: &dyn Any, ctx: &Context) -> Result<Answer, Error> {
+		Solution::part_two(self, downcast::<S>(input)?, ctx)
+	}
+
+	fn part_variant(&self, part: i32, variant: &str, input: &dyn Any, ctx: &Context) -> Result<Answer, Error> {
+		if variant == DEFAULT_VARIANT {
+			return match part {
+				1 => DynSolution::part_one(self, input, ctx),
+				_ => DynSolution::part_two(self, input, ctx),
+			};
+		}
+
+		let found = S::variants().iter()
+			.find(|v| v.part == part && v.name == variant)
+			.ok_or(Error::invalid(format!("Part {part} has no variant named '{variant}'")))?;
+		(found.solve)(self, downcast::<S>(input)?, ctx)
+	}
+}
 
-impl Solution for PlaceholderSolution {
-	fn new(_: &str) -> Self { PlaceholderSolution {} }
-	fn get_file_name(&self) -> &str { panic!("Can't get file name for placeholder solution") }
+fn downcast<S: Solution>(input: &dyn Any) -> Result<&S::Input, Error> {
+	input.downcast_ref::<S::Input>()
+		.ok_or(Error::invalid("Input was not parsed by this solution"))
 }
 
+/// Parses a whitespace separated list of numbers, failing on the first word that isn't one.
+/// The column of the error is relative to the start of `list`.
 pub fn extract_numbers<C, T>(
 	list: &str,
-) -> Result<C, String>
+) -> Result<C, Error>
 	where T: FromStr,
 				C: FromIterator<T>,
 {
-	let numbers = list.split_whitespace()
-		.filter_map(|num| num.parse::<T>().ok())
-		.collect::<C>();
-	Ok(numbers)
+	list.split_whitespace()
+		.map(|num| num.parse::<T>().map_err(|_| {
+			let column = num.as_ptr() as usize - list.as_ptr() as usize + 1;
+			Error::parse(format!("Expected a number, found {num:?}")).at_column(column)
+		}))
+		.collect::<Result<C, Error>>()
 }
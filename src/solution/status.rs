@@ -0,0 +1,48 @@
+use std::fmt::{Display, Formatter};
+
+/// How happy we are with the solution to a part, as shown in the README.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+	/// Solved properly.
+	Solved,
+	/// Solved, but not a very satisfying solution.
+	Unsatisfying,
+	/// Solved, but only by leaning on something that happens to hold for our input.
+	Hacky,
+	/// Not solved yet.
+	Unimplemented,
+}
+
+impl Status {
+	/// The marker the README uses for this status, empty for [Status::Unimplemented].
+	pub fn marker(&self) -> &'static str {
+		match self {
+			Status::Solved => "⭐",
+			Status::Unsatisfying => "🥸",
+			Status::Hacky => "💩",
+			Status::Unimplemented => "",
+		}
+	}
+
+	/// What the marker means, for the legend above the README table.
+	pub fn meaning(&self) -> &'static str {
+		match self {
+			Status::Solved => "solved",
+			Status::Unsatisfying => "solved, but not a very satisfying solution",
+			Status::Hacky => "solved, but...",
+			Status::Unimplemented => "not solved yet",
+		}
+	}
+}
+
+impl Display for Status {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		let name = match self {
+			Status::Solved => "solved",
+			Status::Unsatisfying => "unsatisfying",
+			Status::Hacky => "hacky",
+			Status::Unimplemented => "unimplemented",
+		};
+		write!(f, "{name}")
+	}
+}
@@ -0,0 +1,35 @@
+use crate::error::Error;
+use crate::solution::{Answer, Context, Solution};
+
+/// The name under which the parts of a solution are run unless another variant is asked for.
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// Another implementation of one of the parts, kept next to the one the part normally uses,
+/// e.g. a slow brute force that a faster solution can be checked against.
+pub struct Variant<S: Solution> {
+	/// The part this variant solves.
+	pub part: i32,
+	/// The name to select it with, as in `--variant name`.
+	pub name: &'static str,
+	/// How this variant differs from the default.
+	pub description: &'static str,
+	/// Solves the part, like [Solution::part_one] or [Solution::part_two].
+	pub solve: fn(&S, &S::Input, &Context) -> Result<Answer, Error>,
+}
+
+/// What the registry knows about a [Variant], without the solution it belongs to.
+#[derive(Debug, Clone, Copy)]
+pub struct VariantInfo {
+	/// See [Variant::part].
+	pub part: i32,
+	/// See [Variant::name].
+	pub name: &'static str,
+	/// See [Variant::description].
+	pub description: &'static str,
+}
+
+impl<S: Solution> From<&Variant<S>> for VariantInfo {
+	fn from(variant: &Variant<S>) -> Self {
+		VariantInfo { part: variant.part, name: variant.name, description: variant.description }
+	}
+}
@@ -1,12 +1,21 @@
+/// A compass direction on a grid where `y` grows downwards.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Direction {
+	/// Up, towards `y = 0`.
 	North,
+	/// Down, away from `y = 0`.
 	South,
+	/// Right, away from `x = 0`.
 	East,
+	/// Left, towards `x = 0`.
 	West,
 }
 
 impl Direction {
+	/// Every direction, clockwise from north.
+	pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+	/// The direction pointing the other way.
 	pub fn opposite(&self) -> Direction {
 		match self {
 			Direction::North => Direction::South,
@@ -17,8 +26,65 @@ impl Direction {
 	}
 }
 
+/// One of the eight points of the compass, for moving diagonally as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompassPoint {
+	/// Up.
+	North,
+	/// Up and right.
+	NorthEast,
+	/// Right.
+	East,
+	/// Down and right.
+	SouthEast,
+	/// Down.
+	South,
+	/// Down and left.
+	SouthWest,
+	/// Left.
+	West,
+	/// Up and left.
+	NorthWest,
+}
+
+impl CompassPoint {
+	/// Every point of the compass, clockwise from north.
+	pub const ALL: [CompassPoint; 8] = [
+		CompassPoint::North, CompassPoint::NorthEast, CompassPoint::East, CompassPoint::SouthEast,
+		CompassPoint::South, CompassPoint::SouthWest, CompassPoint::West, CompassPoint::NorthWest,
+	];
+
+	/// How far one step in this direction moves along `x` and `y`.
+	pub fn offset(&self) -> (isize, isize) {
+		match self {
+			CompassPoint::North => (0, -1),
+			CompassPoint::NorthEast => (1, -1),
+			CompassPoint::East => (1, 0),
+			CompassPoint::SouthEast => (1, 1),
+			CompassPoint::South => (0, 1),
+			CompassPoint::SouthWest => (-1, 1),
+			CompassPoint::West => (-1, 0),
+			CompassPoint::NorthWest => (-1, -1),
+		}
+	}
+}
+
+impl From<Direction> for CompassPoint {
+	fn from(direction: Direction) -> Self {
+		match direction {
+			Direction::North => CompassPoint::North,
+			Direction::South => CompassPoint::South,
+			Direction::East => CompassPoint::East,
+			Direction::West => CompassPoint::West,
+		}
+	}
+}
+
+/// A turn relative to the direction something is facing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RelativeDirection {
+	/// Turn clockwise.
 	Right,
+	/// Turn counter-clockwise.
 	Left,
 }
@@ -0,0 +1,347 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut, Range};
+use crate::error::Error;
+use crate::util::{CompassPoint, Direction, Point2D, Point2DExt, Render};
+
+/// A tile of a [Grid] that can be read from and drawn as a single character of the puzzle input.
+pub trait Cell: Sized {
+	/// The tile `c` stands for, or `None` if `c` is not a valid tile.
+	fn from_char(c: char) -> Option<Self>;
+	/// The character the tile is drawn as, the reverse of [Cell::from_char].
+	fn to_char(&self) -> char;
+}
+
+impl Cell for char {
+	fn from_char(c: char) -> Option<Self> { Some(c) }
+	fn to_char(&self) -> char { *self }
+}
+
+/// A rectangular grid stored row by row in one buffer, indexed by `(x, y)` points.
+/// Unlike [crate::util::Matrix2D], every row is guaranteed to be as wide as the others.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+	cells: Vec<T>,
+	width: usize,
+	height: usize,
+}
+
+impl<T> Grid<T> {
+	/// A `width` by `height` grid with every cell set to `value`.
+	pub fn new(
+		width: usize,
+		height: usize,
+		value: T,
+	) -> Grid<T> where T: Clone {
+		Grid { cells: vec![value; width * height], width, height }
+	}
+
+	/// A grid made of `rows`, failing with the line of the first row that is not as wide as the first one.
+	pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, Error> {
+		let width = rows.first().map_or(0, |row| row.len());
+		let height = rows.len();
+		let mut cells = Vec::with_capacity(width * height);
+		for (y, row) in rows.into_iter().enumerate() {
+			if row.len() != width {
+				return Err(Error::parse(format!("Row is {} wide, but the first row is {width} wide", row.len()))
+					.at_line(y + 1)
+					.at_column(row.len().min(width) + 1));
+			}
+			cells.extend(row);
+		}
+
+		Ok(Grid { cells, width, height })
+	}
+
+	/// Reads a grid with one line per row, turning every character into a cell with [Cell::from_char].
+	/// Fails with the line and column of characters that are not a valid cell and of rows
+	/// that are not as wide as the first one.
+	pub fn parse(input: &str) -> Result<Grid<T>, Error> where T: Cell {
+		Grid::parse_with(input, T::from_char)
+	}
+
+	/// Like [Grid::parse], but also returns where `marker` was found, e.g. the starting tile.
+	/// The markers themselves are still turned into cells.
+	pub fn parse_with_markers(
+		input: &str,
+		marker: char,
+	) -> Result<(Grid<T>, Vec<Point2D>), Error> where T: Cell {
+		let markers = input.lines()
+			.enumerate()
+			.flat_map(|(y, line)| line.chars()
+				.enumerate()
+				.filter(|&(_, c)| c == marker)
+				.map(move |(x, _)| (x, y)))
+			.collect();
+
+		Ok((Grid::parse(input)?, markers))
+	}
+
+	/// Like [Grid::parse], but turns the characters into cells with `decode` instead of [Cell].
+	pub fn parse_with(
+		input: &str,
+		decode: impl Fn(char) -> Option<T>,
+	) -> Result<Grid<T>, Error> {
+		let rows = input.lines()
+			.enumerate()
+			.map(|(y, line)| line.chars()
+				.enumerate()
+				.map(|(x, c)| decode(c).ok_or(Error::parse(format!("Unknown char: {c:?}")).at_line(y + 1).at_column(x + 1)))
+				.collect::<Result<Vec<T>, Error>>())
+			.collect::<Result<Vec<Vec<T>>, Error>>()?;
+
+		Grid::from_rows(rows)
+	}
+
+	/// The number of columns.
+	pub fn width(&self) -> usize { self.width }
+	/// The number of rows.
+	pub fn height(&self) -> usize { self.height }
+	/// Every valid `x` coordinate.
+	pub fn x_range(&self) -> Range<usize> { 0..self.width }
+	/// Every valid `y` coordinate.
+	pub fn y_range(&self) -> Range<usize> { 0..self.height }
+
+	/// Whether `point` lies inside the grid.
+	pub fn contains(&self, point: Point2D) -> bool {
+		point.x() < self.width && point.y() < self.height
+	}
+
+	fn offset(&self, point: Point2D) -> Option<usize> {
+		self.contains(point).then(|| point.y() * self.width + point.x())
+	}
+
+	/// The cell at `point`, if it is inside the grid.
+	pub fn get_point(&self, point: Point2D) -> Option<&T> {
+		self.offset(point).map(|i| &self.cells[i])
+	}
+
+	/// The cell at `point` to change, if it is inside the grid.
+	pub fn get_point_mut(&mut self, point: Point2D) -> Option<&mut T> {
+		self.offset(point).map(|i| &mut self.cells[i])
+	}
+
+	/// Replaces the cell at `point`.
+	///
+	/// # Panics
+	/// When `point` is outside the grid, use [Grid::get_point_mut] to check first.
+	pub fn set_point(
+		&mut self,
+		point: Point2D,
+		value: T,
+	) {
+		self[point] = value;
+	}
+
+	/// Whether `point` lies on the outermost ring of cells.
+	pub fn is_edge(&self, point: Point2D) -> bool {
+		self.contains(point)
+			&& (point.x() == 0 || point.y() == 0 || point.x() == self.width - 1 || point.y() == self.height - 1)
+	}
+
+	/// The orthogonal neighbours of `point` that lie inside the grid.
+	pub fn neighbours(&self, point: Point2D) -> impl Iterator<Item = Point2D> + '_ {
+		self.neighbours_with_dir(point).map(|(_, point)| point)
+	}
+
+	/// Like [Grid::neighbours], with the direction every neighbour lies in.
+	pub fn neighbours_with_dir(&self, point: Point2D) -> impl Iterator<Item = (Direction, Point2D)> + '_ {
+		point.neighbours_with_dir().filter(|&(_, next)| self.contains(next))
+	}
+
+	/// The eight points around `point` that lie inside the grid, diagonals included.
+	pub fn surrounding(&self, point: Point2D) -> impl Iterator<Item = Point2D> + '_ {
+		self.surrounding_with_dir(point).map(|(_, point)| point)
+	}
+
+	/// Like [Grid::surrounding], with the direction every neighbour lies in.
+	pub fn surrounding_with_dir(&self, point: Point2D) -> impl Iterator<Item = (CompassPoint, Point2D)> + '_ {
+		point.surrounding_with_dir().filter(|&(_, next)| self.contains(next))
+	}
+
+	/// The orthogonal neighbours of `point` when the edges of the grid wrap around.
+	pub fn wrapping_neighbours(&self, point: Point2D) -> impl Iterator<Item = Point2D> {
+		point.wrapping_neighbours(self.width, self.height)
+	}
+
+	/// Like [Grid::wrapping_neighbours], with the direction every neighbour lies in.
+	pub fn wrapping_neighbours_with_dir(&self, point: Point2D) -> impl Iterator<Item = (Direction, Point2D)> {
+		point.wrapping_neighbours_with_dir(self.width, self.height)
+	}
+
+	/// The row at `y`, if it is inside the grid.
+	pub fn row(&self, y: usize) -> Option<&[T]> {
+		(y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+	}
+
+	/// Every row, from top to bottom.
+	pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+		// chunks panics on a chunk size of 0, but a grid without columns has no cells either
+		self.cells.chunks(self.width.max(1))
+	}
+
+	/// The cells of the column at `x`, from top to bottom. Empty if `x` is outside the grid.
+	pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+		let start = if x < self.width { x } else { self.cells.len() };
+		self.cells[start..].iter().step_by(self.width.max(1))
+	}
+
+	/// Every column, from left to right.
+	pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + '_> + '_ {
+		self.x_range().map(|x| self.column(x))
+	}
+
+	/// Every cell with its point, row by row.
+	pub fn cells(&self) -> impl Iterator<Item = (Point2D, &T)> + '_ {
+		self.cells.iter()
+			.enumerate()
+			.map(|(i, cell)| ((i % self.width, i / self.width), cell))
+	}
+
+	/// A grid of the same size, with `f` applied to every cell.
+	pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+		Grid { cells: self.cells.iter().map(f).collect(), width: self.width, height: self.height }
+	}
+
+	/// A picture of the grid to draw overlays on, with every cell drawn as [Cell::to_char].
+	pub fn render(&self) -> Render where T: Cell {
+		self.render_with(T::to_char)
+	}
+
+	/// A picture of the grid to draw overlays on, with every cell drawn as `draw` returns.
+	pub fn render_with(&self, draw: impl Fn(&T) -> char) -> Render {
+		Render::new(self.width, self.cells.iter().map(draw).collect())
+	}
+
+	/// Every point holding `value`, row by row.
+	pub fn find_all(&self, value: &T) -> Vec<Point2D> where T: PartialEq {
+		self.cells()
+			.filter(|(_, cell)| *cell == value)
+			.map(|(point, _)| point)
+			.collect()
+	}
+}
+
+impl<T: Cell> Display for Grid<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.render())
+	}
+}
+
+impl<T> Index<Point2D> for Grid<T> {
+	type Output = T;
+
+	fn index(&self, point: Point2D) -> &T {
+		match self.offset(point) {
+			Some(i) => &self.cells[i],
+			None => panic!("{point:?} is outside the {}x{} grid", self.width, self.height),
+		}
+	}
+}
+
+impl<T> IndexMut<Point2D> for Grid<T> {
+	fn index_mut(&mut self, point: Point2D) -> &mut T {
+		match self.offset(point) {
+			Some(i) => &mut self.cells[i],
+			None => panic!("{point:?} is outside the {}x{} grid", self.width, self.height),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	enum Tile {
+		Open,
+		Wall,
+	}
+
+	impl Cell for Tile {
+		fn from_char(c: char) -> Option<Self> {
+			match c {
+				'.' | 'S' => Some(Tile::Open),
+				'#' => Some(Tile::Wall),
+				_ => None,
+			}
+		}
+
+		fn to_char(&self) -> char {
+			match self {
+				Tile::Open => '.',
+				Tile::Wall => '#',
+			}
+		}
+	}
+
+	fn numbers() -> Grid<u8> {
+		Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+	}
+
+	#[test]
+	fn from_rows_rejects_ragged_rows() {
+		let error = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5]]).unwrap_err();
+		assert!(matches!(error, Error::Parse { line: Some(2), column: Some(3), .. }), "{error}");
+	}
+
+	#[test]
+	fn empty_grid() {
+		let grid = Grid::<u8>::from_rows(vec![]).unwrap();
+		assert_eq!((grid.width(), grid.height()), (0, 0));
+		assert_eq!(grid.rows().count(), 0);
+		assert_eq!(grid.columns().count(), 0);
+		assert!(!grid.contains((0, 0)));
+	}
+
+	#[test]
+	fn columns() {
+		let grid = numbers();
+		assert_eq!(grid.column(1).copied().collect::<Vec<u8>>(), vec![2, 5]);
+		assert_eq!(grid.column(3).count(), 0);
+		let columns = grid.columns()
+			.map(|column| column.copied().collect::<Vec<u8>>())
+			.collect::<Vec<Vec<u8>>>();
+		assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+	}
+
+	#[test]
+	fn set_point() {
+		let mut grid = numbers();
+		grid.set_point((2, 1), 9);
+		assert_eq!(grid.row(1), Some(&[4, 5, 9][..]));
+	}
+
+	#[test]
+	#[should_panic(expected = "(3, 0) is outside the 3x2 grid")]
+	fn set_point_outside() {
+		numbers().set_point((3, 0), 9);
+	}
+
+	#[test]
+	#[should_panic(expected = "(0, 2) is outside the 3x2 grid")]
+	fn index_outside() {
+		let _ = numbers()[(0, 2)];
+	}
+
+	#[test]
+	fn parse_reports_unknown_chars() {
+		let error = Grid::<Tile>::parse("..#\n.x.\n").unwrap_err();
+		assert!(matches!(error, Error::Parse { line: Some(2), column: Some(2), .. }), "{error}");
+		assert_eq!(error.to_string(), "Parse error on line 2, column 2: Unknown char: 'x'");
+	}
+
+	#[test]
+	fn parse_rejects_ragged_rows() {
+		let error = Grid::<Tile>::parse("..#\n.#\n...\n").unwrap_err();
+		assert!(matches!(error, Error::Parse { line: Some(2), column: Some(3), .. }), "{error}");
+	}
+
+	#[test]
+	fn parse_with_markers() {
+		let (grid, markers) = Grid::<Tile>::parse_with_markers("S.#\n.#S\n", 'S').unwrap();
+		assert_eq!(markers, vec![(0, 0), (2, 1)]);
+		assert_eq!(grid[(0, 0)], Tile::Open);
+		assert_eq!(grid[(2, 1)], Tile::Open);
+		assert_eq!(grid.to_string(), "..#\n.#.\n");
+	}
+}
@@ -0,0 +1,207 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use crate::util::{CompassPoint, Direction, Point2D, Point2DExt};
+
+/// A signed `(x, y)` position or offset where `y` grows downwards.
+/// Unlike [Point2D] it can go below zero, e.g. for grids that repeat forever in every direction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IPoint2D {
+	/// The column of the point.
+	pub x: i64,
+	/// The row of the point.
+	pub y: i64,
+}
+
+impl IPoint2D {
+	/// The origin, and the offset that does not move anywhere.
+	pub const ZERO: IPoint2D = IPoint2D { x: 0, y: 0 };
+
+	/// The point at `x`, `y`.
+	pub const fn new(
+		x: i64,
+		y: i64,
+	) -> IPoint2D {
+		IPoint2D { x, y }
+	}
+
+	/// The point `steps` steps away in `direction`.
+	pub fn move_dir(
+		&self,
+		direction: &Direction,
+		steps: i64,
+	) -> IPoint2D {
+		*self + IPoint2D::from(*direction) * steps
+	}
+
+	/// The number of steps between two points when moving only horizontally and vertically.
+	pub fn manhattan_distance(
+		&self,
+		other: IPoint2D,
+	) -> u64 {
+		self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+	}
+
+	/// The number of steps between two points when diagonal steps are allowed as well.
+	pub fn chebyshev_distance(
+		&self,
+		other: IPoint2D,
+	) -> u64 {
+		self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+	}
+
+	/// The point as a [Point2D], if it lies inside a `width` by `height` grid.
+	pub fn to_point(
+		&self,
+		width: usize,
+		height: usize,
+	) -> Option<Point2D> {
+		let x = usize::try_from(self.x).ok().filter(|&x| x < width)?;
+		let y = usize::try_from(self.y).ok().filter(|&y| y < height)?;
+		Some((x, y))
+	}
+
+	/// The point on a `width` by `height` grid that repeats forever in every direction
+	/// this point lands on, e.g. `(-1, 0)` is the last column of the first row.
+	///
+	/// # Panics
+	/// When the grid is empty.
+	pub fn wrap(
+		&self,
+		width: usize,
+		height: usize,
+	) -> Point2D {
+		let x = self.x.rem_euclid(width as i64) as usize;
+		let y = self.y.rem_euclid(height as i64) as usize;
+		(x, y)
+	}
+}
+
+/// Every point of a grid fits, as a grid can not hold more than `isize::MAX` cells.
+impl From<Point2D> for IPoint2D {
+	fn from(point: Point2D) -> Self {
+		IPoint2D { x: point.x() as i64, y: point.y() as i64 }
+	}
+}
+
+/// Fails for points below zero, see [IPoint2D::to_point] to also check the other edges of a grid.
+impl TryFrom<IPoint2D> for Point2D {
+	type Error = std::num::TryFromIntError;
+
+	fn try_from(point: IPoint2D) -> Result<Self, Self::Error> {
+		Ok((usize::try_from(point.x)?, usize::try_from(point.y)?))
+	}
+}
+
+/// The offset of a single step in the direction.
+impl From<Direction> for IPoint2D {
+	fn from(direction: Direction) -> Self {
+		CompassPoint::from(direction).into()
+	}
+}
+
+/// The offset of a single step in the direction.
+impl From<CompassPoint> for IPoint2D {
+	fn from(compass_point: CompassPoint) -> Self {
+		let (x, y) = compass_point.offset();
+		IPoint2D { x: x as i64, y: y as i64 }
+	}
+}
+
+impl Add for IPoint2D {
+	type Output = IPoint2D;
+
+	fn add(self, other: IPoint2D) -> IPoint2D {
+		IPoint2D { x: self.x + other.x, y: self.y + other.y }
+	}
+}
+
+impl AddAssign for IPoint2D {
+	fn add_assign(&mut self, other: IPoint2D) { *self = *self + other }
+}
+
+impl Sub for IPoint2D {
+	type Output = IPoint2D;
+
+	fn sub(self, other: IPoint2D) -> IPoint2D {
+		IPoint2D { x: self.x - other.x, y: self.y - other.y }
+	}
+}
+
+impl SubAssign for IPoint2D {
+	fn sub_assign(&mut self, other: IPoint2D) { *self = *self - other }
+}
+
+impl Mul<i64> for IPoint2D {
+	type Output = IPoint2D;
+
+	fn mul(self, factor: i64) -> IPoint2D {
+		IPoint2D { x: self.x * factor, y: self.y * factor }
+	}
+}
+
+impl Neg for IPoint2D {
+	type Output = IPoint2D;
+
+	fn neg(self) -> IPoint2D {
+		IPoint2D { x: -self.x, y: -self.y }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn vector_ops() {
+		let a = IPoint2D::new(3, -2);
+		let b = IPoint2D::new(-1, 5);
+		assert_eq!(a + b, IPoint2D::new(2, 3));
+		assert_eq!(a - b, IPoint2D::new(4, -7));
+		assert_eq!(a * 3, IPoint2D::new(9, -6));
+		assert_eq!(-a, IPoint2D::new(-3, 2));
+
+		let mut c = a;
+		c += b;
+		c -= a;
+		assert_eq!(c, b);
+	}
+
+	#[test]
+	fn directions() {
+		assert_eq!(IPoint2D::from(Direction::North), IPoint2D::new(0, -1));
+		assert_eq!(IPoint2D::from(CompassPoint::SouthWest), IPoint2D::new(-1, 1));
+		assert_eq!(IPoint2D::ZERO.move_dir(&Direction::West, 4), IPoint2D::new(-4, 0));
+	}
+
+	#[test]
+	fn distances() {
+		let a = IPoint2D::new(-3, 2);
+		let b = IPoint2D::new(4, -1);
+		assert_eq!(a.manhattan_distance(b), 10);
+		assert_eq!(b.manhattan_distance(a), 10);
+		assert_eq!(a.chebyshev_distance(b), 7);
+		assert_eq!(a.manhattan_distance(a), 0);
+	}
+
+	#[test]
+	fn wrap() {
+		assert_eq!(IPoint2D::new(-1, 0).wrap(3, 2), (2, 0));
+		assert_eq!(IPoint2D::new(-4, -3).wrap(3, 2), (2, 1));
+		assert_eq!(IPoint2D::new(7, 2).wrap(3, 2), (1, 0));
+	}
+
+	#[test]
+	fn to_point() {
+		assert_eq!(IPoint2D::new(2, 1).to_point(3, 2), Some((2, 1)));
+		assert_eq!(IPoint2D::new(3, 1).to_point(3, 2), None);
+		assert_eq!(IPoint2D::new(2, 2).to_point(3, 2), None);
+		assert_eq!(IPoint2D::new(-1, 0).to_point(3, 2), None);
+	}
+
+	#[test]
+	fn point_conversions() {
+		assert_eq!(IPoint2D::from((4, 7)), IPoint2D::new(4, 7));
+		assert_eq!(Point2D::try_from(IPoint2D::new(4, 7)), Ok((4, 7)));
+		assert!(Point2D::try_from(IPoint2D::new(4, -1)).is_err());
+		assert!(Point2D::try_from(IPoint2D::new(-4, 1)).is_err());
+	}
+}
@@ -1,33 +1,79 @@
 use std::ops::Range;
-use crate::util::{Point2D, Point2DExt};
+use crate::util::{CompassPoint, Direction, Point2D, Point2DExt};
 
+/// A grid stored as a list of rows, indexed as `matrix[y][x]`.
 pub type Matrix2D<T> = Vec<Vec<T>>;
 
+/// Coordinate based access to a [Matrix2D].
+/// Every row is expected to be as long as the first one.
 pub trait Matrix2DExt<T> where T: PartialEq {
+	/// The cell at `x`, `y`, if it is inside the matrix.
 	fn get_xy(&self, x: usize, y: usize) -> Option<&T>;
+	/// Replaces the cell at `x`, `y`, doing nothing if it is outside the matrix.
 	fn set_xy(&mut self, x: usize, y: usize, value: T);
+	/// The row at `y`, if it is inside the matrix.
 	fn get_row(&self, y: usize) -> Option<&Vec<T>>;
+	/// The width of the matrix, 0 when it has no rows.
 	fn x_len(&self) -> usize;
+	/// The height of the matrix.
 	fn y_len(&self) -> usize;
 
+	/// Every valid `x` coordinate.
 	fn x_range(&self) -> Range<usize> { 0..self.x_len() }
+	/// Every valid `y` coordinate.
 	fn y_range(&self) -> Range<usize> { 0..self.y_len() }
 
+	/// The cell at `point`, if it is inside the matrix.
 	fn get_point(&self, point: Point2D) -> Option<&T> {
 		self.get_xy(point.x(), point.y())
 	}
 
+	/// Replaces the cell at `point`, doing nothing if it is outside the matrix.
 	fn set_point(&mut self, point: Point2D, value: T) {
 		self.set_xy(point.x(), point.y(), value);
 	}
 
+	/// Whether `point` lies on the outermost ring of cells.
 	fn is_edge(&self, point: Point2D) -> bool {
 		point.x() == 0
 			|| point.y() == 0
-			|| point.x() == self.x_len() - 1
-			|| point.y() == self.y_len() - 1
+			|| point.x() + 1 == self.x_len()
+			|| point.y() + 1 == self.y_len()
 	}
 
+	/// The orthogonal neighbours of `point` that lie inside the matrix.
+	fn neighbours(&self, point: Point2D) -> impl Iterator<Item = Point2D> {
+		self.neighbours_with_dir(point).map(|(_, point)| point)
+	}
+
+	/// Like [Matrix2DExt::neighbours], with the direction every neighbour lies in.
+	fn neighbours_with_dir(&self, point: Point2D) -> impl Iterator<Item = (Direction, Point2D)> {
+		let (width, height) = (self.x_len(), self.y_len());
+		point.neighbours_with_dir().filter(move |(_, next)| next.x() < width && next.y() < height)
+	}
+
+	/// The eight points around `point` that lie inside the matrix, diagonals included.
+	fn surrounding(&self, point: Point2D) -> impl Iterator<Item = Point2D> {
+		self.surrounding_with_dir(point).map(|(_, point)| point)
+	}
+
+	/// Like [Matrix2DExt::surrounding], with the direction every neighbour lies in.
+	fn surrounding_with_dir(&self, point: Point2D) -> impl Iterator<Item = (CompassPoint, Point2D)> {
+		let (width, height) = (self.x_len(), self.y_len());
+		point.surrounding_with_dir().filter(move |(_, next)| next.x() < width && next.y() < height)
+	}
+
+	/// The orthogonal neighbours of `point` when the edges of the matrix wrap around.
+	fn wrapping_neighbours(&self, point: Point2D) -> impl Iterator<Item = Point2D> {
+		point.wrapping_neighbours(self.x_len(), self.y_len())
+	}
+
+	/// Like [Matrix2DExt::wrapping_neighbours], with the direction every neighbour lies in.
+	fn wrapping_neighbours_with_dir(&self, point: Point2D) -> impl Iterator<Item = (Direction, Point2D)> {
+		point.wrapping_neighbours_with_dir(self.x_len(), self.y_len())
+	}
+
+	/// Every point holding `value`, row by row.
 	fn find_all(&self, value: &T) -> Vec<Point2D> {
 		let mut points = vec![];
 		for y in self.y_range() {
@@ -60,7 +106,7 @@ impl<T> Matrix2DExt<T> for Matrix2D<T> where T: PartialEq {
 	}
 
 	fn get_row(&self, y: usize) -> Option<&Vec<T>> { self.get(y) }
-	fn x_len(&self) -> usize { self[0].len() }
+	fn x_len(&self) -> usize { self.first().map_or(0, |row| row.len()) }
 
 	fn y_len(&self) -> usize { self.len() }
 }
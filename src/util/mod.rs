@@ -1,10 +1,21 @@
+//! Grid and geometry helpers shared by the solutions.
+
 mod point2;
+mod ipoint2;
 mod matrix2;
 mod direction;
+mod grid;
+mod render;
 
 pub use point2::Point2D;
 pub use point2::Point2DExt;
+pub use ipoint2::IPoint2D;
 pub use matrix2::Matrix2D;
 pub use matrix2::Matrix2DExt;
+pub use direction::CompassPoint;
 pub use direction::Direction;
 pub use direction::RelativeDirection;
+pub use grid::Cell;
+pub use grid::Grid;
+pub use render::Colour;
+pub use render::Render;
@@ -1,11 +1,16 @@
-use crate::util::{Direction, Matrix2D, Matrix2DExt};
+use crate::util::{CompassPoint, Direction, Matrix2D, Matrix2DExt};
 
+/// An `(x, y)` position on a grid where `y` grows downwards.
 pub type Point2D = (usize, usize);
 
+/// Navigation around a [Point2D]. Moves that would go below zero return `None`.
 pub trait Point2DExt {
+	/// The column of the point.
 	fn x(&self) -> usize;
+	/// The row of the point.
 	fn y(&self) -> usize;
 
+	/// The neighbouring point in `direction`.
 	fn move_dir(
 		&self,
 		direction: &Direction,
@@ -18,6 +23,7 @@ pub trait Point2DExt {
 		}
 	}
 
+	/// The neighbouring point in `direction`, or `None` without a direction.
 	fn move_opt_dir(
 		&self,
 		direction: Option<Direction>,
@@ -28,6 +34,79 @@ pub trait Point2DExt {
 		}
 	}
 
+	/// The point one step towards `compass_point`, which may be diagonal.
+	fn move_compass(
+		&self,
+		compass_point: &CompassPoint,
+	) -> Option<Point2D> {
+		let (dx, dy) = compass_point.offset();
+		Some((self.x().checked_add_signed(dx)?, self.y().checked_add_signed(dy)?))
+	}
+
+	/// The neighbouring point in `direction` on a `width` by `height` grid whose edges wrap around,
+	/// so stepping off one side comes back in on the other.
+	///
+	/// # Panics
+	/// When the grid is empty.
+	fn wrapping_move_dir(
+		&self,
+		direction: &Direction,
+		width: usize,
+		height: usize,
+	) -> Point2D {
+		let (dx, dy) = CompassPoint::from(*direction).offset();
+		let x = (self.x() % width + width).wrapping_add_signed(dx) % width;
+		let y = (self.y() % height + height).wrapping_add_signed(dy) % height;
+		(x, y)
+	}
+
+	/// The points above, right of, below and left of this one, leaving out the ones below zero.
+	fn neighbours(self) -> impl Iterator<Item = Point2D> where Self: Sized {
+		self.neighbours_with_dir().map(|(_, point)| point)
+	}
+
+	/// Like [Point2DExt::neighbours], with the direction every neighbour lies in.
+	fn neighbours_with_dir(self) -> impl Iterator<Item = (Direction, Point2D)> where Self: Sized {
+		let point = (self.x(), self.y());
+		Direction::ALL.into_iter()
+			.filter_map(move |dir| point.move_dir(&dir).map(|next| (dir, next)))
+	}
+
+	/// The eight points around this one, diagonals included, leaving out the ones below zero.
+	fn surrounding(self) -> impl Iterator<Item = Point2D> where Self: Sized {
+		self.surrounding_with_dir().map(|(_, point)| point)
+	}
+
+	/// Like [Point2DExt::surrounding], with the direction every neighbour lies in.
+	fn surrounding_with_dir(self) -> impl Iterator<Item = (CompassPoint, Point2D)> where Self: Sized {
+		let point = (self.x(), self.y());
+		CompassPoint::ALL.into_iter()
+			.filter_map(move |dir| point.move_compass(&dir).map(|next| (dir, next)))
+	}
+
+	/// The four neighbours on a `width` by `height` grid whose edges wrap around, see [Point2DExt::wrapping_move_dir].
+	/// Empty when the grid is.
+	fn wrapping_neighbours(
+		self,
+		width: usize,
+		height: usize,
+	) -> impl Iterator<Item = Point2D> where Self: Sized {
+		self.wrapping_neighbours_with_dir(width, height).map(|(_, point)| point)
+	}
+
+	/// Like [Point2DExt::wrapping_neighbours], with the direction every neighbour lies in.
+	fn wrapping_neighbours_with_dir(
+		self,
+		width: usize,
+		height: usize,
+	) -> impl Iterator<Item = (Direction, Point2D)> where Self: Sized {
+		let point = (self.x(), self.y());
+		Direction::ALL.into_iter()
+			.filter(move |_| width > 0 && height > 0)
+			.map(move |dir| (dir, point.wrapping_move_dir(&dir, width, height)))
+	}
+
+	/// The point above this one.
 	fn north(&self) -> Option<Point2D> {
 		if self.y() > 0 {
 			Some((self.x(), self.y() - 1))
@@ -36,6 +115,7 @@ pub trait Point2DExt {
 		}
 	}
 
+	/// The point left of this one.
 	fn west(&self) -> Option<Point2D> {
 		if self.x() > 0 {
 			Some((self.x() - 1, self.y()))
@@ -44,6 +124,7 @@ pub trait Point2DExt {
 		}
 	}
 
+	/// The number of steps between two points when moving only horizontally and vertically.
 	fn manhattan_distance(
 		&self,
 		end: Point2D,
@@ -53,14 +134,21 @@ pub trait Point2DExt {
 		x_diff + y_diff
 	}
 
+	/// The point below this one.
 	fn south(&self) -> Option<Point2D> { Some((self.x(), self.y() + 1)) }
+	/// The point right of this one.
 	fn east(&self) -> Option<Point2D> { Some((self.x() + 1, self.y())) }
 
+	/// Same as [Point2DExt::north].
 	fn up(&self) -> Option<Point2D> { self.north() }
+	/// Same as [Point2DExt::south].
 	fn down(&self) -> Option<Point2D> { self.south() }
+	/// Same as [Point2DExt::west].
 	fn left(&self) -> Option<Point2D> { self.west() }
+	/// Same as [Point2DExt::east].
 	fn right(&self) -> Option<Point2D> { self.east() }
 
+	/// Whether the point lies within `0..=max_x` and `0..=max_y`.
 	fn in_grid(
 		&self,
 		max_x: usize,
@@ -69,11 +157,12 @@ pub trait Point2DExt {
 		self.x() <= max_x && self.y() <= max_y
 	}
 
+	/// Whether the point lies within the bounds of `matrix`. Never true for an empty matrix.
 	fn in_matrix<T : PartialEq>(
 		&self,
 		matrix: &Matrix2D<T>,
 	) -> bool {
-		self.in_grid(matrix.x_len() - 1, matrix.y_len() - 1)
+		self.x() < matrix.x_len() && self.y() < matrix.y_len()
 	}
 }
 
@@ -81,3 +170,103 @@ impl Point2DExt for Point2D {
 	fn x(&self) -> usize { self.0 }
 	fn y(&self) -> usize { self.1 }
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::util::CompassPoint;
+
+	/// A 3 by 2 matrix, so the far corner is `(2, 1)`.
+	fn matrix() -> Matrix2D<char> {
+		vec![vec!['.'; 3]; 2]
+	}
+
+	#[test]
+	fn neighbours_at_zero() {
+		assert_eq!((0, 0).neighbours().collect::<Vec<Point2D>>(), vec![(1, 0), (0, 1)]);
+		assert_eq!(
+			(0, 1).neighbours_with_dir().collect::<Vec<(Direction, Point2D)>>(),
+			vec![(Direction::North, (0, 0)), (Direction::East, (1, 1)), (Direction::South, (0, 2))],
+		);
+	}
+
+	#[test]
+	fn neighbours_at_far_edge() {
+		assert_eq!(matrix().neighbours((2, 1)).collect::<Vec<Point2D>>(), vec![(2, 0), (1, 1)]);
+		assert_eq!(
+			matrix().neighbours_with_dir((2, 0)).collect::<Vec<(Direction, Point2D)>>(),
+			vec![(Direction::South, (2, 1)), (Direction::West, (1, 0))],
+		);
+	}
+
+	#[test]
+	fn empty_matrix() {
+		let empty: Matrix2D<char> = vec![];
+		assert_eq!((empty.x_len(), empty.y_len()), (0, 0));
+		assert!(!(0, 0).in_matrix(&empty));
+		assert!(!(0, 0).in_matrix(&vec![Vec::<char>::new()]));
+		assert_eq!(empty.neighbours((0, 0)).count(), 0);
+		assert_eq!(empty.surrounding((0, 0)).count(), 0);
+		assert_eq!(empty.wrapping_neighbours((0, 0)).count(), 0);
+	}
+
+	#[test]
+	fn surrounding_at_zero() {
+		assert_eq!((0, 0).surrounding().collect::<Vec<Point2D>>(), vec![(1, 0), (1, 1), (0, 1)]);
+		assert_eq!(
+			(1, 0).surrounding_with_dir().collect::<Vec<(CompassPoint, Point2D)>>(),
+			vec![
+				(CompassPoint::East, (2, 0)),
+				(CompassPoint::SouthEast, (2, 1)),
+				(CompassPoint::South, (1, 1)),
+				(CompassPoint::SouthWest, (0, 1)),
+				(CompassPoint::West, (0, 0)),
+			],
+		);
+	}
+
+	#[test]
+	fn surrounding_at_far_edge() {
+		assert_eq!(matrix().surrounding((2, 1)).collect::<Vec<Point2D>>(), vec![(2, 0), (1, 1), (1, 0)]);
+		assert_eq!(
+			matrix().surrounding_with_dir((1, 1)).collect::<Vec<(CompassPoint, Point2D)>>(),
+			vec![
+				(CompassPoint::North, (1, 0)),
+				(CompassPoint::NorthEast, (2, 0)),
+				(CompassPoint::East, (2, 1)),
+				(CompassPoint::West, (0, 1)),
+				(CompassPoint::NorthWest, (0, 0)),
+			],
+		);
+	}
+
+	#[test]
+	fn wrapping_neighbours() {
+		assert_eq!((0, 0).wrapping_neighbours(3, 2).collect::<Vec<Point2D>>(), vec![(0, 1), (1, 0), (0, 1), (2, 0)]);
+		assert_eq!(
+			(2, 1).wrapping_neighbours_with_dir(3, 2).collect::<Vec<(Direction, Point2D)>>(),
+			vec![
+				(Direction::North, (2, 0)),
+				(Direction::East, (0, 1)),
+				(Direction::South, (2, 0)),
+				(Direction::West, (1, 1)),
+			],
+		);
+		assert_eq!(matrix().wrapping_neighbours((2, 0)).collect::<Vec<Point2D>>(), vec![(2, 1), (0, 0), (2, 1), (1, 0)]);
+		assert_eq!((0, 0).wrapping_neighbours(0, 0).count(), 0);
+	}
+
+	#[test]
+	fn wrapping_move_dir_outside_the_grid() {
+		assert_eq!((4, 3).wrapping_move_dir(&Direction::East, 3, 2), (2, 1));
+		assert_eq!((3, 2).wrapping_move_dir(&Direction::West, 3, 2), (2, 0));
+	}
+
+	#[test]
+	fn compass_points_match_directions() {
+		for direction in Direction::ALL {
+			assert_eq!((1, 1).move_compass(&CompassPoint::from(direction)), (1, 1).move_dir(&direction));
+		}
+		assert_eq!((0, 1).move_compass(&CompassPoint::NorthWest), None);
+	}
+}
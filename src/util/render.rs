@@ -0,0 +1,143 @@
+use std::fmt::{Display, Formatter};
+use crate::util::{Direction, Point2D, Point2DExt};
+
+/// The colours [Render] can draw overlays in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+	/// ANSI red.
+	Red,
+	/// ANSI green.
+	Green,
+	/// ANSI yellow.
+	Yellow,
+	/// ANSI blue.
+	Blue,
+	/// ANSI magenta.
+	Magenta,
+	/// ANSI cyan.
+	Cyan,
+}
+
+impl Colour {
+	fn ansi_code(&self) -> u8 {
+		match self {
+			Colour::Red => 31,
+			Colour::Green => 32,
+			Colour::Yellow => 33,
+			Colour::Blue => 34,
+			Colour::Magenta => 35,
+			Colour::Cyan => 36,
+		}
+	}
+}
+
+/// A picture of a grid with overlays on top, e.g. the points a part visited or the path it took.
+/// Get one from [crate::util::Grid::render] and print it with [Display].
+///
+/// Colours are only drawn with [Render::ansi], so overlays that should also show up
+/// in plain text have to change the characters, like [Render::mark] and [Render::path] do.
+#[derive(Debug, Clone)]
+pub struct Render {
+	width: usize,
+	chars: Vec<char>,
+	colours: Vec<Option<Colour>>,
+	ansi: bool,
+}
+
+impl Render {
+	pub(crate) fn new(
+		width: usize,
+		chars: Vec<char>,
+	) -> Render {
+		let colours = vec![None; chars.len()];
+		Render { width, chars, colours, ansi: false }
+	}
+
+	fn offset(&self, point: Point2D) -> Option<usize> {
+		let height = self.chars.len().checked_div(self.width).unwrap_or(0);
+		(point.x() < self.width && point.y() < height).then(|| point.y() * self.width + point.x())
+	}
+
+	/// Whether to draw the colours of the overlays with ANSI escape codes.
+	pub fn ansi(self, ansi: bool) -> Render {
+		Render { ansi, ..self }
+	}
+
+	/// Colours every point in `points`. Points outside the grid are ignored.
+	pub fn highlight(
+		mut self,
+		points: impl IntoIterator<Item = Point2D>,
+		colour: Colour,
+	) -> Render {
+		for point in points {
+			if let Some(i) = self.offset(point) {
+				self.colours[i] = Some(colour);
+			}
+		}
+		self
+	}
+
+	/// Draws every point in `points` as `c`, in `colour`. Points outside the grid are ignored.
+	pub fn mark(
+		mut self,
+		points: impl IntoIterator<Item = Point2D>,
+		c: char,
+		colour: Colour,
+	) -> Render {
+		for point in points {
+			if let Some(i) = self.offset(point) {
+				self.chars[i] = c;
+				self.colours[i] = Some(colour);
+			}
+		}
+		self
+	}
+
+	/// Draws `path` as arrows pointing from every point to the next one, in `colour`.
+	/// Steps between points that are not next to each other keep the original character.
+	pub fn path(
+		mut self,
+		path: &[Point2D],
+		colour: Colour,
+	) -> Render {
+		for (i, &point) in path.iter().enumerate() {
+			let Some(offset) = self.offset(point) else { continue; };
+			let arrow = path.get(i + 1)
+				.and_then(|&next| direction(point, next))
+				.map(|dir| match dir {
+					Direction::North => '^',
+					Direction::South => 'v',
+					Direction::East => '>',
+					Direction::West => '<',
+				});
+
+			if let Some(arrow) = arrow { self.chars[offset] = arrow; }
+			self.colours[offset] = Some(colour);
+		}
+		self
+	}
+}
+
+/// The direction of the single step from `from` to `to`, if they are next to each other.
+fn direction(
+	from: Point2D,
+	to: Point2D,
+) -> Option<Direction> {
+	[Direction::North, Direction::South, Direction::East, Direction::West].into_iter()
+		.find(|dir| from.move_dir(dir) == Some(to))
+}
+
+impl Display for Render {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		for (row, colours) in self.chars.chunks(self.width.max(1)).zip(self.colours.chunks(self.width.max(1))) {
+			for (&c, colour) in row.iter().zip(colours) {
+				match colour {
+					Some(colour) if self.ansi => write!(f, "\x1B[{}m{c}\x1B[0m", colour.ansi_code())?,
+					_ => write!(f, "{c}")?,
+				}
+			}
+			writeln!(f)?;
+		}
+		Ok(())
+	}
+}